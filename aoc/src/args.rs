@@ -0,0 +1,59 @@
+//! Arguments for the small tools under each day's `src/bin`, which take a few
+//! options and an input file, and give up with their usage line on anything
+//! they don't know.
+
+use std::env;
+use std::fs;
+use std::iter::Skip;
+use std::process;
+use std::str::FromStr;
+
+/// Prints `message` and exits with status 2, for bad arguments and for input
+/// that can't be read or parsed alike.
+pub fn fail(message: &str) -> ! {
+    eprintln!("{message}");
+    process::exit(2);
+}
+
+/// The text of `file`, or a failure saying why not.
+pub fn read(file: &str) -> String {
+    fs::read_to_string(file).unwrap_or_else(|e| fail(&format!("can't read {file}: {e}")))
+}
+
+/// The arguments after the tool's name, and the usage line to fail with.
+pub struct Args {
+    usage: &'static str,
+    args: Skip<env::Args>,
+}
+
+impl Args {
+    pub fn new(usage: &'static str) -> Args {
+        Args {
+            usage,
+            args: env::args().skip(1),
+        }
+    }
+
+    /// Fails with the usage line.
+    pub fn usage(&self) -> ! {
+        fail(self.usage)
+    }
+
+    /// The value after an option, which has to be there.
+    pub fn value(&mut self) -> String {
+        self.args.next().unwrap_or_else(|| self.usage())
+    }
+
+    /// The value after an option, which has to parse.
+    pub fn number<T: FromStr>(&mut self) -> T {
+        self.value().parse().unwrap_or_else(|_| self.usage())
+    }
+}
+
+impl Iterator for Args {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        self.args.next()
+    }
+}
@@ -0,0 +1,118 @@
+//! Steps through a trace recorded with `AOC_TRACE=<file>`.
+//!
+//! ```text
+//! cargo run --bin trace -- <file> [step]
+//! ```
+//!
+//! With a step, prints that step and exits. Otherwise reads commands from
+//! stdin; `h` lists them.
+
+use std::io::{self, BufRead, Write};
+
+use aoc::trace::{Event, Trace};
+
+const HELP: &str = "\
+enter, n [count]  forward
+p [count]         back
+s / S             next / previous state change
+g <step>          go to a step
+q                 show the queue
+h                 this help
+x                 exit";
+
+fn show(trace: &Trace, at: usize) {
+    let step = &trace.steps[at];
+    let changed = match &step.event {
+        Event::State(changed) => changed.as_slice(),
+        _ => &[],
+    };
+    println!(
+        "step {}/{}: {} (queue: {})",
+        at + 1,
+        trace.len(),
+        step.event,
+        step.queue_len
+    );
+    for (i, row) in step.rows.iter().enumerate() {
+        let marker = if changed.contains(&i) { '>' } else { ' ' };
+        println!("{marker} {row}");
+    }
+}
+
+fn show_queue(trace: &Trace, at: usize) {
+    let queue = trace.queue_at(at);
+    println!("{} queued, oldest first:", queue.len());
+    for item in queue {
+        println!("  {item}");
+    }
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let Some(path) = args.first() else {
+        eprintln!("usage: trace <file> [step]");
+        std::process::exit(2);
+    };
+    let text = std::fs::read_to_string(path).unwrap_or_else(|e| {
+        eprintln!("{path}: {e}");
+        std::process::exit(1);
+    });
+    let trace = Trace::parse(&text).unwrap_or_else(|e| {
+        eprintln!("{path}: {e}");
+        std::process::exit(1);
+    });
+    if trace.is_empty() {
+        println!("{path}: no steps recorded");
+        return;
+    }
+    let last = trace.len() - 1;
+
+    if let Some(step) = args.get(1) {
+        match step.parse::<usize>() {
+            Ok(step) if (1..=trace.len()).contains(&step) => show(&trace, step - 1),
+            _ => {
+                eprintln!("step should be 1 to {}", trace.len());
+                std::process::exit(2);
+            }
+        }
+        return;
+    }
+
+    let mut at = 0;
+    show(&trace, at);
+    let stdin = io::stdin();
+    loop {
+        print!("> ");
+        io::stdout().flush().ok();
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line).unwrap_or(0) == 0 {
+            return;
+        }
+        let mut words = line.split_whitespace();
+        let command = words.next().unwrap_or("n");
+        let count = words.next().and_then(|n| n.parse::<usize>().ok());
+        match command {
+            "n" => at = at.saturating_add(count.unwrap_or(1)).min(last),
+            "p" => at = at.saturating_sub(count.unwrap_or(1)),
+            "s" => at = trace.next_state(at).unwrap_or(last),
+            "S" => at = trace.prev_state(at).unwrap_or(0),
+            "g" => match count {
+                Some(step) if (1..=trace.len()).contains(&step) => at = step - 1,
+                _ => {
+                    println!("step should be 1 to {}", trace.len());
+                    continue;
+                }
+            },
+            "q" => {
+                show_queue(&trace, at);
+                continue;
+            }
+            "x" => return,
+            _ => {
+                println!("{HELP}");
+                continue;
+            }
+        }
+        show(&trace, at);
+    }
+}
@@ -0,0 +1,443 @@
+//! Bit-packed sets and grids.
+//!
+//! A visited set over a grid is mostly a yes or no per tile, and a
+//! `BTreeSet<(usize, usize)>` spends a node and two words on each one. These
+//! keep one bit per member instead, so a whole visited set for a puzzle input
+//! fits in a few kilobytes, clones with a `memcpy` and counts with `popcnt`.
+
+const WORD: usize = u64::BITS as usize;
+
+/// Moves every bit in `words` `by` places towards the higher end, dropping the
+/// ones that fall off it.
+fn shift_up(words: &mut [u64], by: usize) {
+    let (skip, bits) = (by / WORD, by % WORD);
+    for i in (0..words.len()).rev() {
+        let from = |i: Option<usize>| i.and_then(|i| words.get(i)).copied().unwrap_or(0);
+        let high = from(i.checked_sub(skip));
+        let low = from(i.checked_sub(skip + 1));
+        words[i] = match bits {
+            0 => high,
+            _ => high << bits | low >> (WORD - bits),
+        };
+    }
+}
+
+/// Moves every bit in `words` `by` places towards the lower end, dropping the
+/// ones that fall off it.
+fn shift_down(words: &mut [u64], by: usize) {
+    let (skip, bits) = (by / WORD, by % WORD);
+    for i in 0..words.len() {
+        let from = |i: usize| words.get(i).copied().unwrap_or(0);
+        let low = from(i + skip);
+        let high = from(i + skip + 1);
+        words[i] = match bits {
+            0 => low,
+            _ => low >> bits | high << (WORD - bits),
+        };
+    }
+}
+
+/// Clears the bits of `words` from `len` on.
+fn clear_from(words: &mut [u64], len: usize) {
+    let (full, bits) = (len / WORD, len % WORD);
+    if let Some(partial) = words.get_mut(full) {
+        if bits > 0 {
+            *partial &= (1 << bits) - 1;
+        }
+    }
+    let start = full + usize::from(bits > 0);
+    words.iter_mut().skip(start).for_each(|word| *word = 0);
+}
+
+/// A set of numbers below a capacity fixed when it's made.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitSet {
+    capacity: usize,
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    pub fn new(capacity: usize) -> BitSet {
+        BitSet {
+            capacity,
+            words: vec![0; capacity.div_ceil(WORD)],
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Adds `n`, returning whether it wasn't there yet.
+    ///
+    /// # Panics
+    ///
+    /// When `n` is past the capacity.
+    pub fn insert(&mut self, n: usize) -> bool {
+        assert!(
+            n < self.capacity,
+            "{n} is past the capacity of {}",
+            self.capacity
+        );
+        let (word, bit) = (&mut self.words[n / WORD], 1 << (n % WORD));
+        let new = *word & bit == 0;
+        *word |= bit;
+        new
+    }
+
+    /// Whether `n` is in the set. Nothing past the capacity ever is.
+    pub fn contains(&self, n: usize) -> bool {
+        n < self.capacity && self.words[n / WORD] & 1 << (n % WORD) != 0
+    }
+
+    /// Takes `n` out, returning whether it was there.
+    pub fn remove(&mut self, n: usize) -> bool {
+        if !self.contains(n) {
+            return false;
+        }
+        self.words[n / WORD] &= !(1 << (n % WORD));
+        true
+    }
+
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    pub fn len(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&word| word == 0)
+    }
+
+    /// The members, smallest first.
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(i, &word)| {
+            let mut left = word;
+            std::iter::from_fn(move || {
+                (left != 0).then(|| {
+                    let bit = left.trailing_zeros() as usize;
+                    left &= left - 1;
+                    i * WORD + bit
+                })
+            })
+        })
+    }
+
+    /// Adds every member of `other`, which should have the same capacity.
+    pub fn union_with(&mut self, other: &BitSet) {
+        assert_eq!(self.capacity, other.capacity, "capacities differ");
+        for (word, other) in self.words.iter_mut().zip(&other.words) {
+            *word |= other;
+        }
+    }
+
+    /// Adds `by` to every member, dropping the ones that end up past the
+    /// capacity.
+    pub fn shift_up(&mut self, by: usize) {
+        shift_up(&mut self.words, by);
+        clear_from(&mut self.words, self.capacity);
+    }
+
+    /// Takes `by` from every member, dropping the ones that would go below 0.
+    pub fn shift_down(&mut self, by: usize) {
+        shift_down(&mut self.words, by);
+    }
+}
+
+/// A rectangle of tiles with a few flag bits each: one for a visited set, or
+/// up to eight for something like the directions a beam has crossed a tile in.
+///
+/// Each row starts on a fresh word, so rows can be shifted on their own.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    width: usize,
+    height: usize,
+    /// Bits per tile: 1, 2, 4 or 8, so a tile never straddles two words.
+    bits: usize,
+    row_words: usize,
+    words: Vec<u64>,
+}
+
+impl BitGrid {
+    /// A grid with one bit per tile, for use as a set of positions.
+    pub fn new(width: usize, height: usize) -> BitGrid {
+        BitGrid::with_flags(width, height, 1)
+    }
+
+    /// A grid with room for `flags` bits per tile.
+    ///
+    /// # Panics
+    ///
+    /// When `flags` is more than 8.
+    pub fn with_flags(width: usize, height: usize, flags: usize) -> BitGrid {
+        assert!(flags <= 8, "a tile holds at most 8 flags, not {flags}");
+        let bits = flags.max(1).next_power_of_two();
+        let row_words = (width * bits).div_ceil(WORD);
+        BitGrid {
+            width,
+            height,
+            bits,
+            row_words,
+            words: vec![0; row_words * height],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The word holding tile `(x, y)` and how far up it the tile's bits start.
+    fn locate(&self, (x, y): (usize, usize)) -> Option<(usize, usize)> {
+        (x < self.width && y < self.height).then(|| {
+            let bit = x * self.bits;
+            (y * self.row_words + bit / WORD, bit % WORD)
+        })
+    }
+
+    fn mask(&self) -> u64 {
+        (1 << self.bits) - 1
+    }
+
+    /// The flags set on a tile. Tiles off the grid have none.
+    pub fn flags(&self, at: (usize, usize)) -> u8 {
+        self.locate(at).map_or(0, |(word, shift)| {
+            (self.words[word] >> shift & self.mask()) as u8
+        })
+    }
+
+    /// Sets `flags` on a tile, returning whether any of them weren't set yet.
+    ///
+    /// # Panics
+    ///
+    /// When the tile is off the grid.
+    pub fn insert_flags(&mut self, at: (usize, usize), flags: u8) -> bool {
+        let (word, shift) = self
+            .locate(at)
+            .unwrap_or_else(|| panic!("{at:?} is off the {}x{} grid", self.width, self.height));
+        let flags = (u64::from(flags) & self.mask()) << shift;
+        let new = self.words[word] & flags != flags;
+        self.words[word] |= flags;
+        new
+    }
+
+    /// Clears `flags` on a tile, returning whether any of them were set.
+    pub fn remove_flags(&mut self, at: (usize, usize), flags: u8) -> bool {
+        let Some((word, shift)) = self.locate(at) else {
+            return false;
+        };
+        let flags = (u64::from(flags) & self.mask()) << shift;
+        let had = self.words[word] & flags != 0;
+        self.words[word] &= !flags;
+        had
+    }
+
+    /// Adds a tile to the set, returning whether it wasn't there yet.
+    pub fn insert(&mut self, at: (usize, usize)) -> bool {
+        self.insert_flags(at, 1)
+    }
+
+    /// Whether a tile has any flag set.
+    pub fn contains(&self, at: (usize, usize)) -> bool {
+        self.flags(at) != 0
+    }
+
+    /// Clears every flag on a tile, returning whether it had any.
+    pub fn remove(&mut self, at: (usize, usize)) -> bool {
+        self.remove_flags(at, u8::MAX)
+    }
+
+    pub fn clear(&mut self) {
+        self.words.fill(0);
+    }
+
+    /// Folds each tile's flags into its lowest bit.
+    fn any_flag(&self, mut word: u64) -> u64 {
+        let mut spread = 1;
+        while spread < self.bits {
+            word |= word >> spread;
+            spread *= 2;
+        }
+        let lowest = u64::MAX / self.mask();
+        word & lowest
+    }
+
+    /// How many tiles have any flag set.
+    pub fn count(&self) -> usize {
+        self.words
+            .iter()
+            .map(|&word| self.any_flag(word).count_ones() as usize)
+            .sum()
+    }
+
+    /// The tiles with any flag set, a row at a time from the top.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let per_word = WORD / self.bits;
+        self.words.iter().enumerate().flat_map(move |(i, &word)| {
+            let (y, first) = (i / self.row_words, i % self.row_words * per_word);
+            let mut left = self.any_flag(word);
+            std::iter::from_fn(move || {
+                (left != 0).then(|| {
+                    let x = first + left.trailing_zeros() as usize / self.bits;
+                    left &= left - 1;
+                    (x, y)
+                })
+            })
+        })
+    }
+
+    /// Moves every tile in row `y` `by` places, right when `by` is positive
+    /// and left when it's negative. Tiles pushed off the end are dropped.
+    pub fn shift_row(&mut self, y: usize, by: isize) {
+        let start = y * self.row_words;
+        let row = &mut self.words[start..start + self.row_words];
+        let bits = by.unsigned_abs() * self.bits;
+        if by > 0 {
+            shift_up(row, bits);
+            clear_from(row, self.width * self.bits);
+        } else {
+            shift_down(row, bits);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::*;
+    use crate::prop;
+
+    #[derive(Debug, Clone, Copy)]
+    enum Op {
+        Insert(usize),
+        Remove(usize),
+        Up(usize),
+        Down(usize),
+    }
+
+    #[test]
+    fn sets_agree_with_btree_sets() {
+        prop::check(
+            |source| {
+                let capacity = source.int(1..=200usize);
+                let ops = source.vec(0..=40, |source| {
+                    let n = source.int(0..=capacity + 1);
+                    match source.int(0..=3u8) {
+                        0 => Op::Insert(n.min(capacity - 1)),
+                        1 => Op::Remove(n),
+                        2 => Op::Up(n),
+                        _ => Op::Down(n),
+                    }
+                });
+                (capacity, ops)
+            },
+            |(capacity, ops)| {
+                let mut set = BitSet::new(*capacity);
+                let mut model = BTreeSet::new();
+                ops.iter().all(|&op| {
+                    let agreed = match op {
+                        Op::Insert(n) => set.insert(n) == model.insert(n),
+                        Op::Remove(n) => set.remove(n) == model.remove(&n),
+                        Op::Up(by) => {
+                            set.shift_up(by);
+                            model = model
+                                .iter()
+                                .map(|n| n + by)
+                                .filter(|n| n < capacity)
+                                .collect();
+                            true
+                        }
+                        Op::Down(by) => {
+                            set.shift_down(by);
+                            model = model.iter().filter_map(|n| n.checked_sub(by)).collect();
+                            true
+                        }
+                    };
+                    agreed && set.len() == model.len() && set.iter().eq(model.iter().copied())
+                })
+            },
+        );
+    }
+
+    #[test]
+    fn grids_agree_with_btree_sets() {
+        prop::check(
+            |source| {
+                let (width, height) = (source.int(1..=140usize), source.int(1..=4usize));
+                let ops = source.vec(0..=40, |source| {
+                    let at = (source.int(0..=width), source.int(0..=height - 1));
+                    let by = source.int(-(width as isize)..=width as isize);
+                    (source.int(0..=2u8), at, by)
+                });
+                (width, height, ops)
+            },
+            |(width, height, ops)| {
+                let mut grid = BitGrid::new(*width, *height);
+                // Kept as (y, x) so it iterates in the grid's order.
+                let mut model = BTreeSet::new();
+                ops.iter().all(|&(kind, (x, y), by)| {
+                    let agreed = match kind {
+                        0 if x < *width => grid.insert((x, y)) == model.insert((y, x)),
+                        0 => true,
+                        1 => grid.remove((x, y)) == model.remove(&(y, x)),
+                        _ => {
+                            grid.shift_row(y, by);
+                            model = model
+                                .iter()
+                                .filter_map(|&(row, x)| match row == y {
+                                    true => x
+                                        .checked_add_signed(by)
+                                        .filter(|x| x < width)
+                                        .map(|x| (row, x)),
+                                    false => Some((row, x)),
+                                })
+                                .collect();
+                            true
+                        }
+                    };
+                    agreed
+                        && grid.count() == model.len()
+                        && grid.iter().eq(model.iter().map(|&(y, x)| (x, y)))
+                })
+            },
+        );
+    }
+
+    #[test]
+    fn word_boundaries_and_flags() {
+        let mut set = BitSet::new(130);
+        assert!(set.insert(0) && set.insert(64) && set.insert(129));
+        assert!(!set.insert(64));
+        assert_eq!((set.len(), set.contains(130)), (3, false));
+        set.shift_up(1);
+        assert_eq!(set.iter().collect::<Vec<_>>(), [1, 65]);
+        set.shift_down(65);
+        assert_eq!(set.iter().collect::<Vec<_>>(), [0]);
+        assert!(set.remove(0) && set.is_empty());
+
+        let mut grid = BitGrid::new(70, 3);
+        assert!(grid.insert((69, 1)) && grid.insert((0, 2)));
+        assert!(!grid.contains((70, 1)) && !grid.remove((70, 1)));
+        grid.shift_row(1, -5);
+        assert_eq!(grid.iter().collect::<Vec<_>>(), [(64, 1), (0, 2)]);
+        grid.shift_row(1, 6);
+        assert_eq!(grid.count(), 1);
+
+        let mut beams = BitGrid::with_flags(20, 2, 4);
+        assert!(beams.insert_flags((17, 1), 0b0101));
+        assert!(!beams.insert_flags((17, 1), 0b0100));
+        assert!(beams.insert_flags((3, 0), 0b1000));
+        assert_eq!((beams.flags((17, 1)), beams.count()), (0b0101, 2));
+        assert!(beams.remove_flags((17, 1), 0b0001));
+        assert_eq!(beams.iter().collect::<Vec<_>>(), [(3, 0), (17, 1)]);
+    }
+}
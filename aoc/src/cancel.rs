@@ -0,0 +1,49 @@
+//! Cooperative cancellation for solvers that can run for a long time.
+//!
+//! The runner hands a [`Cancel`] to each solver and trips it when the solver
+//! goes over its time or memory budget. Nothing is interrupted: a solver that
+//! takes a token is expected to check it in its hot loop and give up, and
+//! whatever it returns afterwards is thrown away.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Default)]
+pub struct Cancel {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl Cancel {
+    pub fn new() -> Cancel {
+        Cancel::default()
+    }
+
+    /// A token nobody else holds, for running a solver outside the runner.
+    pub fn never() -> Cancel {
+        Cancel::default()
+    }
+
+    /// Asks every holder of this token to stop.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clones_share_the_flag() {
+        let cancel = Cancel::new();
+        let handed_out = cancel.clone();
+        assert!(!handed_out.is_cancelled());
+        cancel.cancel();
+        assert!(handed_out.is_cancelled());
+        assert!(!Cancel::never().is_cancelled());
+    }
+}
@@ -0,0 +1,491 @@
+//! Exact arithmetic for the days that would otherwise reach for `f64`.
+//!
+//! [`Rational`] keeps a reduced fraction in `i128` and quietly switches to
+//! arbitrary precision when an operation would overflow, so comparisons like
+//! "are these slopes equal" or "is this point inside the box" never depend on
+//! rounding. The line helpers work on integer points and directions and hand
+//! back exact [`Rational`] parameters.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use num_bigint::BigInt;
+use num_integer::Integer;
+use num_traits::{One, Signed, ToPrimitive, Zero};
+
+/// Largest `r` with `r * r <= n`.
+pub fn isqrt(n: u128) -> u128 {
+    if n < 2 {
+        return n;
+    }
+    // Newton's method from an overestimate only ever walks down.
+    let mut x = 1u128 << (n.ilog2() / 2 + 1);
+    loop {
+        let y = (x + n / x) / 2;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
+fn gcd(a: u128, b: u128) -> u128 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+/// A fraction in lowest terms with a positive denominator.
+#[derive(Debug, Clone)]
+pub struct Rational(Repr);
+
+#[derive(Debug, Clone)]
+enum Repr {
+    Small { num: i128, den: i128 },
+    // Only used when the value doesn't fit in `Small`.
+    Big { num: BigInt, den: BigInt },
+}
+
+impl Rational {
+    /// `num / den`, reduced.
+    ///
+    /// # Panics
+    ///
+    /// If `den` is zero.
+    pub fn new(num: i128, den: i128) -> Rational {
+        assert!(den != 0, "rational with a zero denominator");
+        Rational::small(num, den).unwrap_or_else(|| Rational::big(num.into(), den.into()))
+    }
+
+    pub fn zero() -> Rational {
+        Rational(Repr::Small { num: 0, den: 1 })
+    }
+
+    fn small(num: i128, den: i128) -> Option<Rational> {
+        let g = gcd(num.unsigned_abs(), den.unsigned_abs());
+        let g = i128::try_from(g).ok()?;
+        let (mut num, mut den) = (num / g, den / g);
+        if den < 0 {
+            num = num.checked_neg()?;
+            den = den.checked_neg()?;
+        }
+        Some(Rational(Repr::Small { num, den }))
+    }
+
+    fn big(num: BigInt, den: BigInt) -> Rational {
+        assert!(!den.is_zero(), "rational with a zero denominator");
+        let g = num.gcd(&den);
+        let (mut num, mut den) = (num / &g, den / g);
+        if den.is_negative() {
+            num = -num;
+            den = -den;
+        }
+        match (num.to_i128(), den.to_i128()) {
+            (Some(num), Some(den)) => Rational(Repr::Small { num, den }),
+            _ => Rational(Repr::Big { num, den }),
+        }
+    }
+
+    fn parts(&self) -> (BigInt, BigInt) {
+        match &self.0 {
+            Repr::Small { num, den } => ((*num).into(), (*den).into()),
+            Repr::Big { num, den } => (num.clone(), den.clone()),
+        }
+    }
+
+    pub fn signum(&self) -> i32 {
+        match &self.0 {
+            Repr::Small { num, .. } => num.signum() as i32,
+            Repr::Big { num, .. } => num.signum().to_i32().unwrap_or(0),
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.signum() == 0
+    }
+
+    pub fn is_positive(&self) -> bool {
+        self.signum() > 0
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.signum() < 0
+    }
+
+    pub fn is_integer(&self) -> bool {
+        match &self.0 {
+            Repr::Small { den, .. } => *den == 1,
+            Repr::Big { den, .. } => den.is_one(),
+        }
+    }
+
+    /// The value as an `i128`, if it is a whole number that fits.
+    pub fn to_i128(&self) -> Option<i128> {
+        match self.0 {
+            Repr::Small { num, den: 1 } => Some(num),
+            _ => None,
+        }
+    }
+
+    /// Nearest `f64`, for display and nothing else.
+    pub fn to_f64(&self) -> f64 {
+        match &self.0 {
+            Repr::Small { num, den } => *num as f64 / *den as f64,
+            Repr::Big { num, den } => {
+                num.to_f64().unwrap_or(f64::NAN) / den.to_f64().unwrap_or(f64::NAN)
+            }
+        }
+    }
+
+    /// Largest whole number not above `self`.
+    pub fn floor(&self) -> Rational {
+        let (num, den) = self.parts();
+        Rational::big(num.div_floor(&den), BigInt::one())
+    }
+
+    /// Smallest whole number not below `self`.
+    pub fn ceil(&self) -> Rational {
+        -(-self).floor()
+    }
+
+    pub fn recip(&self) -> Rational {
+        let (num, den) = self.parts();
+        Rational::big(den, num)
+    }
+
+    fn add_ref(&self, other: &Rational) -> Rational {
+        if let (Repr::Small { num: a, den: b }, Repr::Small { num: c, den: d }) =
+            (&self.0, &other.0)
+        {
+            // a/b + c/d over lcm(b, d) keeps the intermediates small.
+            let g = gcd(b.unsigned_abs(), d.unsigned_abs()) as i128;
+            let sum = a
+                .checked_mul(d / g)
+                .zip(c.checked_mul(b / g))
+                .and_then(|(x, y)| x.checked_add(y));
+            let den = (b / g).checked_mul(*d);
+            if let Some(r) = sum.zip(den).and_then(|(n, d)| Rational::small(n, d)) {
+                return r;
+            }
+        }
+        let ((a, b), (c, d)) = (self.parts(), other.parts());
+        Rational::big(a * &d + c * &b, b * d)
+    }
+
+    fn mul_ref(&self, other: &Rational) -> Rational {
+        if let (Repr::Small { num: a, den: b }, Repr::Small { num: c, den: d }) =
+            (&self.0, &other.0)
+        {
+            // Cancel across first so a/b * c/d only overflows when the result does.
+            let g1 = gcd(a.unsigned_abs(), d.unsigned_abs()) as i128;
+            let g2 = gcd(c.unsigned_abs(), b.unsigned_abs()) as i128;
+            let num = (a / g1).checked_mul(c / g2);
+            let den = (b / g2).checked_mul(d / g1);
+            if let Some(r) = num.zip(den).and_then(|(n, d)| Rational::small(n, d)) {
+                return r;
+            }
+        }
+        let ((a, b), (c, d)) = (self.parts(), other.parts());
+        Rational::big(a * c, b * d)
+    }
+}
+
+impl From<i128> for Rational {
+    fn from(n: i128) -> Rational {
+        Rational(Repr::Small { num: n, den: 1 })
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(n: i64) -> Rational {
+        Rational::from(n as i128)
+    }
+}
+
+impl Neg for &Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        match &self.0 {
+            Repr::Small { num, den } => match num.checked_neg() {
+                Some(num) => Rational(Repr::Small { num, den: *den }),
+                None => Rational::big(-BigInt::from(*num), (*den).into()),
+            },
+            Repr::Big { num, den } => Rational::big(-num, den.clone()),
+        }
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        -&self
+    }
+}
+
+macro_rules! binary_op {
+    ($trait:ident, $method:ident, |$a:ident, $b:ident| $body:expr) => {
+        impl $trait<&Rational> for &Rational {
+            type Output = Rational;
+
+            fn $method(self, $b: &Rational) -> Rational {
+                let $a = self;
+                $body
+            }
+        }
+
+        impl $trait<Rational> for Rational {
+            type Output = Rational;
+
+            fn $method(self, other: Rational) -> Rational {
+                (&self).$method(&other)
+            }
+        }
+
+        impl $trait<&Rational> for Rational {
+            type Output = Rational;
+
+            fn $method(self, other: &Rational) -> Rational {
+                (&self).$method(other)
+            }
+        }
+    };
+}
+
+binary_op!(Add, add, |a, b| a.add_ref(b));
+binary_op!(Sub, sub, |a, b| a.add_ref(&-b));
+binary_op!(Mul, mul, |a, b| a.mul_ref(b));
+binary_op!(Div, div, |a, b| a.mul_ref(&b.recip()));
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Rational) -> Ordering {
+        if let (Repr::Small { num: a, den: b }, Repr::Small { num: c, den: d }) =
+            (&self.0, &other.0)
+        {
+            if let (Some(x), Some(y)) = (a.checked_mul(*d), c.checked_mul(*b)) {
+                return x.cmp(&y);
+            }
+        }
+        let ((a, b), (c, d)) = (self.parts(), other.parts());
+        (a * d).cmp(&(c * b))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Rational) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Rational {
+    fn eq(&self, other: &Rational) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Rational {}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (num, den) = self.parts();
+        if den.is_one() {
+            write!(f, "{num}")
+        } else {
+            write!(f, "{num}/{den}")
+        }
+    }
+}
+
+/// The line `point + t * direction`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<const N: usize> {
+    pub point: [i128; N],
+    pub direction: [i128; N],
+}
+
+pub type Line2 = Line<2>;
+pub type Line3 = Line<3>;
+
+/// How two lines meet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Intersection<const N: usize> {
+    /// `a` reaches `at` after `t` steps of its direction, `b` after `u`.
+    Point {
+        t: Rational,
+        u: Rational,
+        at: [Rational; N],
+    },
+    /// Same direction, never meeting.
+    Parallel,
+    /// The same line twice.
+    Coincident,
+    /// Different directions that never meet (3D only).
+    Skew,
+}
+
+impl<const N: usize> Line<N> {
+    /// The point reached after `t` steps.
+    pub fn at(&self, t: &Rational) -> [Rational; N] {
+        std::array::from_fn(|i| {
+            Rational::from(self.point[i]) + t * &Rational::from(self.direction[i])
+        })
+    }
+}
+
+fn rationals<const N: usize>(v: [i128; N]) -> [Rational; N] {
+    v.map(Rational::from)
+}
+
+fn difference<const N: usize>(a: [i128; N], b: [i128; N]) -> [Rational; N] {
+    std::array::from_fn(|i| Rational::from(a[i]) - Rational::from(b[i]))
+}
+
+fn cross2(a: &[Rational; 2], b: &[Rational; 2]) -> Rational {
+    &a[0] * &b[1] - &a[1] * &b[0]
+}
+
+fn cross3(a: &[Rational; 3], b: &[Rational; 3]) -> [Rational; 3] {
+    [
+        &a[1] * &b[2] - &a[2] * &b[1],
+        &a[2] * &b[0] - &a[0] * &b[2],
+        &a[0] * &b[1] - &a[1] * &b[0],
+    ]
+}
+
+fn dot<const N: usize>(a: &[Rational; N], b: &[Rational; N]) -> Rational {
+    a.iter()
+        .zip(b)
+        .fold(Rational::zero(), |acc, (x, y)| acc + x * y)
+}
+
+/// Where two lines in the plane cross.
+pub fn intersect_2d(a: &Line2, b: &Line2) -> Intersection<2> {
+    let (da, db) = (rationals(a.direction), rationals(b.direction));
+    let w = difference(b.point, a.point);
+    let denom = cross2(&da, &db);
+    if denom.is_zero() {
+        return if cross2(&w, &da).is_zero() {
+            Intersection::Coincident
+        } else {
+            Intersection::Parallel
+        };
+    }
+    let t = cross2(&w, &db) / &denom;
+    let u = cross2(&w, &da) / &denom;
+    let at = a.at(&t);
+    Intersection::Point { t, u, at }
+}
+
+/// Where two lines in space meet, if they do.
+pub fn intersect_3d(a: &Line3, b: &Line3) -> Intersection<3> {
+    let (da, db) = (rationals(a.direction), rationals(b.direction));
+    let w = difference(b.point, a.point);
+    let n = cross3(&da, &db);
+    if n.iter().all(Rational::is_zero) {
+        return if cross3(&w, &da).iter().all(Rational::is_zero) {
+            Intersection::Coincident
+        } else {
+            Intersection::Parallel
+        };
+    }
+    if !dot(&w, &n).is_zero() {
+        return Intersection::Skew;
+    }
+    let nn = dot(&n, &n);
+    let t = dot(&cross3(&w, &db), &n) / &nn;
+    let u = dot(&cross3(&w, &da), &n) / &nn;
+    let at = a.at(&t);
+    Intersection::Point { t, u, at }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn isqrt_is_exact_near_squares() {
+        assert_eq!(isqrt(0), 0);
+        assert_eq!(isqrt(15), 3);
+        assert_eq!(isqrt(16), 4);
+        let big = (1u128 << 63) + 12345;
+        assert_eq!(isqrt(big * big - 1), big - 1);
+        assert_eq!(isqrt(big * big), big);
+        assert_eq!(isqrt(u128::MAX), u64::MAX as u128);
+    }
+
+    #[test]
+    fn overflow_falls_back_to_big_and_back() {
+        let huge = Rational::from(i128::MAX);
+        let squared = &huge * &huge;
+        assert!(squared > huge);
+        assert_eq!(squared / &huge, huge);
+        assert_eq!(Rational::new(6, -4), Rational::new(-3, 2));
+        assert_eq!(Rational::new(-7, 2).floor(), Rational::from(-4i128));
+        assert_eq!(Rational::new(-7, 2).ceil(), Rational::from(-3i128));
+        assert_eq!(Rational::new(1, 3).to_string(), "1/3");
+    }
+
+    #[test]
+    fn lines_in_the_plane() {
+        let a = Line2 {
+            point: [19, 13],
+            direction: [-2, 1],
+        };
+        let b = Line2 {
+            point: [18, 19],
+            direction: [-1, -1],
+        };
+        let Intersection::Point { t, u, at } = intersect_2d(&a, &b) else {
+            panic!("lines should cross");
+        };
+        assert!(t.is_positive() && u.is_positive());
+        assert_eq!(at, [Rational::new(43, 3), Rational::new(46, 3)]);
+
+        // A vertical line has no slope, but still crosses.
+        let vertical = Line2 {
+            point: [5, 0],
+            direction: [0, 3],
+        };
+        let Intersection::Point { at, .. } = intersect_2d(&a, &vertical) else {
+            panic!("lines should cross");
+        };
+        assert_eq!(at, [Rational::from(5i128), Rational::from(20i128)]);
+
+        let shifted = Line2 {
+            point: [20, 13],
+            direction: [4, -2],
+        };
+        assert_eq!(intersect_2d(&a, &shifted), Intersection::Parallel);
+        let same = Line2 {
+            point: [17, 14],
+            direction: [4, -2],
+        };
+        assert_eq!(intersect_2d(&a, &same), Intersection::Coincident);
+    }
+
+    #[test]
+    fn lines_in_space() {
+        let a = Line3 {
+            point: [0, 0, 0],
+            direction: [1, 1, 1],
+        };
+        let b = Line3 {
+            point: [2, 0, 2],
+            direction: [0, 1, 0],
+        };
+        let Intersection::Point { t, u, .. } = intersect_3d(&a, &b) else {
+            panic!("lines should meet");
+        };
+        assert_eq!((t, u), (Rational::from(2i128), Rational::from(2i128)));
+
+        let skew = Line3 {
+            point: [2, 0, 3],
+            direction: [0, 1, 0],
+        };
+        assert_eq!(intersect_3d(&a, &skew), Intersection::Skew);
+    }
+}
@@ -0,0 +1,159 @@
+//! Puts puzzle input into the one shape the parsers expect.
+//!
+//! Inputs saved on Windows arrive with CRLF line endings, sometimes a BOM and
+//! sometimes as UTF-16, and editors disagree about trailing whitespace and the
+//! final newline. After [`normalize`] the text is UTF-8, uses `\n` between
+//! lines, has no trailing whitespace on any line and does not end in a newline.
+//! [`example`] additionally strips the indentation that examples pasted into
+//! tests pick up.
+
+use std::borrow::Cow;
+
+use crate::parse::{self, ParseError};
+
+const BOM: char = '\u{feff}';
+
+/// An error just past `valid`, the text decoded before things went wrong.
+fn error_after(valid: &str, message: &str) -> ParseError {
+    let line_start = valid.rfind('\n').map_or(0, |i| i + 1);
+    ParseError {
+        line: valid.matches('\n').count() + 1,
+        column: valid[line_start..].chars().count() + 1,
+        message: message.to_string(),
+    }
+}
+
+/// Decodes a file as read from disk: UTF-8 with or without a BOM, or UTF-16
+/// when it starts with a UTF-16 BOM.
+pub fn decode(bytes: &[u8]) -> parse::Result<String> {
+    let utf16 = |unit: fn([u8; 2]) -> u16| {
+        let units = bytes[2..].chunks_exact(2);
+        let odd = !units.remainder().is_empty();
+        let mut text = String::new();
+        for c in char::decode_utf16(units.map(|pair| unit([pair[0], pair[1]]))) {
+            match c {
+                Ok(c) => text.push(c),
+                Err(_) => return Err(error_after(&text, "input is not valid UTF-16")),
+            }
+        }
+        if odd {
+            return Err(error_after(
+                &text,
+                "input ends halfway through a UTF-16 unit",
+            ));
+        }
+        Ok(text)
+    };
+    match bytes {
+        [0xff, 0xfe, ..] => utf16(u16::from_le_bytes),
+        [0xfe, 0xff, ..] => utf16(u16::from_be_bytes),
+        _ => match std::str::from_utf8(bytes) {
+            Ok(text) => Ok(text.to_string()),
+            // Everything up to the bad byte is fine, so point just past it.
+            Err(e) => Err(error_after(
+                std::str::from_utf8(&bytes[..e.valid_up_to()]).unwrap_or_default(),
+                "input is not valid UTF-8",
+            )),
+        },
+    }
+}
+
+fn is_clean(input: &str) -> bool {
+    !input.starts_with(BOM)
+        && !input.contains('\r')
+        && input
+            .lines()
+            .all(|line| line.trim_end().len() == line.len())
+}
+
+/// Strips a BOM, turns CRLF and lone CRs into LF, trims every line's trailing
+/// whitespace and drops trailing blank lines and the final newline.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    // Most input only needs its final newline dropped, which is just a slice.
+    if is_clean(input) {
+        return Cow::Borrowed(input.trim_end());
+    }
+    let input = input.strip_prefix(BOM).unwrap_or(input);
+    let input = input.replace("\r\n", "\n").replace('\r', "\n");
+    let lines: Vec<&str> = input.lines().map(str::trim_end).collect();
+    Cow::Owned(lines.join("\n").trim_end().to_string())
+}
+
+/// [`normalize`], then drops leading blank lines and the indentation shared by
+/// every line. A first line with no indentation is left out when working out
+/// the shared indentation, since in an indented string literal it's the one
+/// that sits right after the opening quote.
+pub fn example(input: &str) -> String {
+    let input = normalize(input);
+    let lines: Vec<&str> = input.lines().skip_while(|line| line.is_empty()).collect();
+    let indent = |line: &str| line.len() - line.trim_start_matches([' ', '\t']).len();
+    let skip_first = lines.first().is_some_and(|line| indent(line) == 0);
+    let common = lines
+        .iter()
+        .skip(usize::from(skip_first))
+        .filter(|line| !line.is_empty())
+        .map(|line| indent(line))
+        .min()
+        .unwrap_or(0);
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            if i == 0 && skip_first {
+                line
+            } else {
+                line.get(common..).unwrap_or("")
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn windows_input() {
+        let input = "\u{feff}rn=1,cm-  \r\nqp=3\rab=5\t\r\n\r\n";
+        assert_eq!(normalize(input), "rn=1,cm-\nqp=3\nab=5");
+        assert!(matches!(normalize("1abc2\n"), Cow::Borrowed("1abc2")));
+
+        let utf16 = |units: &[u16]| -> Vec<u8> {
+            [0xfeff]
+                .iter()
+                .chain(units)
+                .flat_map(|unit| unit.to_le_bytes())
+                .collect()
+        };
+        let text = utf16(&['#' as u16, ' ' as u16, 'A' as u16, '\r' as u16, '\n' as u16]);
+        assert_eq!(normalize(&decode(&text).unwrap()), "# A");
+
+        // A lone surrogate, and a byte left over at the end.
+        let error = decode(&utf16(&['#' as u16, '\n' as u16, '.' as u16, 0xd800])).unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.message.as_str()),
+            (2, 2, "input is not valid UTF-16")
+        );
+        let mut odd = utf16(&['#' as u16, '.' as u16]);
+        odd.push(b'\n');
+        let error = decode(&odd).unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.message.as_str()),
+            (1, 3, "input ends halfway through a UTF-16 unit")
+        );
+
+        let error = decode(b"ok\nbad \xff").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 5));
+    }
+
+    #[test]
+    fn indented_examples() {
+        let input = "two1nine
+            eightwothree
+              abcone2threexyz
+            ";
+        assert_eq!(example(input), "two1nine\neightwothree\n  abcone2threexyz");
+        assert_eq!(example("\n    #.#\n    ...\n"), "#.#\n...");
+    }
+}
@@ -0,0 +1,15 @@
+//! Shared helpers for the Advent of Code 2023 solvers.
+//!
+//! Every `dayN` crate pulls this in by path, so anything added here should
+//! stay small and free of puzzle-specific logic.
+
+pub mod args;
+pub mod bits;
+pub mod cancel;
+pub mod exact;
+pub mod input;
+pub mod parse;
+pub mod prop;
+pub mod render;
+pub mod rng;
+pub mod trace;
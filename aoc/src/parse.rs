@@ -0,0 +1,372 @@
+//! Zero-copy building blocks for reading puzzle input.
+//!
+//! A [`Scanner`] is a cursor over a window of the original input. Everything it
+//! hands back is a `&str` borrowed from that input, and every [`ParseError`]
+//! knows the line and column it happened at, even when the scanner was split
+//! off from a single line or block.
+
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line in the original input.
+    pub line: usize,
+    /// 1-based column, counted in chars.
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub type Result<T> = std::result::Result<T, ParseError>;
+
+/// A cursor over `src[pos..end]` that remembers the whole of `src` for error positions.
+#[derive(Debug, Clone, Copy)]
+pub struct Scanner<'a> {
+    src: &'a str,
+    pos: usize,
+    end: usize,
+}
+
+/// A `name = (left, right)` line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Record<'a> {
+    pub name: &'a str,
+    pub left: &'a str,
+    pub right: &'a str,
+}
+
+/// A rectangular block of text, one `&str` per row.
+#[derive(Debug, Clone)]
+pub struct Grid<'a> {
+    pub rows: Vec<&'a str>,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl<'a> Scanner<'a> {
+    pub fn new(src: &'a str) -> Scanner<'a> {
+        Scanner {
+            src,
+            pos: 0,
+            end: src.len(),
+        }
+    }
+
+    fn sub(&self, start: usize, end: usize) -> Scanner<'a> {
+        Scanner {
+            src: self.src,
+            pos: start,
+            end,
+        }
+    }
+
+    /// What is left to read.
+    pub fn rest(&self) -> &'a str {
+        &self.src[self.pos..self.end]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pos >= self.end
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    /// Line and column of a byte offset into the original input.
+    fn position(&self, offset: usize) -> (usize, usize) {
+        let before = &self.src[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let line = before.matches('\n').count() + 1;
+        let column = before[line_start..].chars().count() + 1;
+        (line, column)
+    }
+
+    fn offset_of(&self, slice: &str) -> usize {
+        let base = self.src.as_ptr() as usize;
+        let at = slice.as_ptr() as usize;
+        if at < base || at > base + self.src.len() {
+            return self.pos;
+        }
+        at - base
+    }
+
+    /// An error pointing at the current position.
+    pub fn error(&self, message: impl Into<String>) -> ParseError {
+        self.error_at_offset(self.pos, message)
+    }
+
+    /// An error pointing at `slice`, which must have been borrowed from this input.
+    pub fn error_at(&self, slice: &str, message: impl Into<String>) -> ParseError {
+        self.error_at_offset(self.offset_of(slice), message)
+    }
+
+    fn error_at_offset(&self, offset: usize, message: impl Into<String>) -> ParseError {
+        let (line, column) = self.position(offset);
+        ParseError {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// Consumes a single char.
+    pub fn next_char(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += c.len_utf8();
+        Some(c)
+    }
+
+    /// Skips spaces and tabs, but not newlines.
+    pub fn skip_spaces(&mut self) {
+        self.take_while(|c| c == ' ' || c == '\t');
+    }
+
+    pub fn take_while(&mut self, f: impl Fn(char) -> bool) -> &'a str {
+        let rest = self.rest();
+        let len = rest.find(|c| !f(c)).unwrap_or(rest.len());
+        self.pos += len;
+        &rest[..len]
+    }
+
+    /// Consumes `tag` exactly.
+    pub fn tag(&mut self, tag: &str) -> Result<()> {
+        if self.rest().starts_with(tag) {
+            self.pos += tag.len();
+            Ok(())
+        } else {
+            Err(self.error(format!("expected `{tag}`")))
+        }
+    }
+
+    /// A run of letters, digits and underscores, after any leading spaces.
+    pub fn word(&mut self) -> Result<&'a str> {
+        self.skip_spaces();
+        let word = self.take_while(|c| c.is_alphanumeric() || c == '_');
+        if word.is_empty() {
+            return Err(self.error("expected a word"));
+        }
+        Ok(word)
+    }
+
+    /// A run of anything but whitespace, after any leading spaces.
+    pub fn token(&mut self) -> Result<&'a str> {
+        self.skip_spaces();
+        let token = self.take_while(|c| !c.is_whitespace());
+        if token.is_empty() {
+            return Err(self.error("expected a token"));
+        }
+        Ok(token)
+    }
+
+    /// An optionally signed integer, after any leading spaces.
+    pub fn int<T: FromStr>(&mut self) -> Result<T> {
+        self.skip_spaces();
+        let start = self.pos;
+        if matches!(self.peek(), Some('-') | Some('+')) {
+            self.pos += 1;
+        }
+        if self.take_while(|c| c.is_ascii_digit()).is_empty() {
+            self.pos = start;
+            return Err(self.error("expected an integer"));
+        }
+        let digits = &self.src[start..self.pos];
+        digits
+            .parse()
+            .map_err(|_| self.error_at_offset(start, format!("`{digits}` is out of range")))
+    }
+
+    /// Every integer up to the end, separated by spaces and/or commas.
+    pub fn ints<T: FromStr>(&mut self) -> Result<Vec<T>> {
+        let mut ints = vec![];
+        loop {
+            self.take_while(|c| c.is_whitespace() || c == ',');
+            if self.is_empty() {
+                return Ok(ints);
+            }
+            ints.push(self.int()?);
+        }
+    }
+
+    /// Fails if anything other than whitespace is left.
+    pub fn finish(&mut self) -> Result<()> {
+        self.take_while(char::is_whitespace);
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self.error(format!("unexpected `{}`", self.rest())))
+        }
+    }
+
+    /// The remainder split on `delim`, one scanner per piece.
+    pub fn split(&self, delim: char) -> impl Iterator<Item = Scanner<'a>> + 'a {
+        let this = *self;
+        let mut start = self.pos;
+        let pieces = self.rest().split(delim);
+        pieces.map(move |piece| {
+            let scanner = this.sub(start, start + piece.len());
+            start += piece.len() + delim.len_utf8();
+            scanner
+        })
+    }
+
+    /// Splits the remainder around the first `delim`.
+    pub fn split_once(&self, delim: &str) -> Result<(Scanner<'a>, Scanner<'a>)> {
+        match self.rest().find(delim) {
+            Some(i) => Ok((
+                self.sub(self.pos, self.pos + i),
+                self.sub(self.pos + i + delim.len(), self.end),
+            )),
+            None => Err(self.error(format!("expected `{delim}`"))),
+        }
+    }
+
+    /// One scanner per line, without the line break.
+    pub fn lines(&self) -> impl Iterator<Item = Scanner<'a>> + 'a {
+        let this = *self;
+        let mut start = self.pos;
+        self.rest().split_inclusive('\n').map(move |line| {
+            let text = line.strip_suffix('\n').unwrap_or(line);
+            let scanner = this.sub(start, start + text.len());
+            start += line.len();
+            scanner
+        })
+    }
+
+    /// Groups of lines separated by one or more blank lines.
+    pub fn blocks(&self) -> impl Iterator<Item = Scanner<'a>> + 'a {
+        let mut lines = self.lines().peekable();
+        std::iter::from_fn(move || {
+            while lines
+                .next_if(|line| line.rest().trim().is_empty())
+                .is_some()
+            {}
+            let first = lines.next()?;
+            let mut end = first.end;
+            while let Some(line) = lines.next_if(|line| !line.rest().trim().is_empty()) {
+                end = line.end;
+            }
+            Some(first.sub(first.pos, end))
+        })
+    }
+
+    /// Splits `key: values` into the key and a scanner over the values.
+    pub fn header(&self) -> Result<(Scanner<'a>, Scanner<'a>)> {
+        let (key, mut values) = self.split_once(":")?;
+        values.skip_spaces();
+        Ok((key, values))
+    }
+
+    /// Reads a `name = (left, right)` line.
+    pub fn record(&self) -> Result<Record<'a>> {
+        let mut line = *self;
+        let name = line.word()?;
+        line.skip_spaces();
+        line.tag("=")?;
+        line.skip_spaces();
+        line.tag("(")?;
+        let left = line.word()?;
+        line.tag(",")?;
+        let right = line.word()?;
+        line.tag(")")?;
+        line.finish()?;
+        Ok(Record { name, left, right })
+    }
+
+    /// Reads the remainder as a rectangular grid, rejecting ragged rows.
+    pub fn grid(&self) -> Result<Grid<'a>> {
+        let mut rows = vec![];
+        let mut width = 0;
+        for line in self.lines() {
+            let row = line.rest();
+            let row_width = row.chars().count();
+            if rows.is_empty() {
+                width = row_width;
+            } else if row_width != width {
+                return Err(line.error(format!("row is {row_width} wide, expected {width}")));
+            }
+            rows.push(row);
+        }
+        let height = rows.len();
+        Ok(Grid {
+            rows,
+            width,
+            height,
+        })
+    }
+}
+
+impl<'a> Grid<'a> {
+    /// Every cell as `(x, y, c)`, row by row.
+    pub fn cells(&self) -> impl Iterator<Item = (usize, usize, char)> + '_ {
+        self.rows
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| row.chars().enumerate().map(move |(x, c)| (x, y, c)))
+    }
+}
+
+/// Every integer in `s`, separated by spaces and/or commas.
+pub fn ints<T: FromStr>(s: &str) -> Result<Vec<T>> {
+    Scanner::new(s).ints()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ints_with_signs() {
+        let result = ints::<i64>("19, 13, 30 -2  1 +4");
+        assert_eq!(result, Ok(vec![19, 13, 30, -2, 1, 4]));
+    }
+
+    #[test]
+    fn errors_point_into_the_original_input() {
+        let input = "Card 1: 41 48 | 83 86\nCard 2: 13 x2 | 61 30";
+        let card = Scanner::new(input).lines().nth(1).unwrap();
+        let (_, numbers) = card.header().unwrap();
+        let (mut winning, _) = numbers.split_once("|").unwrap();
+        let error = winning.ints::<u32>().unwrap_err();
+        assert_eq!((error.line, error.column), (2, 12));
+    }
+
+    #[test]
+    fn blocks_and_records() {
+        let input = "LLR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\n\n\nZZZ = (ZZZ, ZZZ)\n";
+        let blocks: Vec<_> = Scanner::new(input).blocks().collect();
+        assert_eq!(blocks.len(), 3);
+        let records: Vec<_> = blocks[1]
+            .lines()
+            .map(|line| line.record().unwrap())
+            .collect();
+        assert_eq!(
+            records[1],
+            Record {
+                name: "BBB",
+                left: "AAA",
+                right: "ZZZ"
+            }
+        );
+    }
+
+    #[test]
+    fn grid_rejects_ragged_rows() {
+        let grid = Scanner::new("#.#\n...\n").grid().unwrap();
+        assert_eq!((grid.width, grid.height), (3, 2));
+        let error = Scanner::new("#.#\n..\n").grid().unwrap_err();
+        assert_eq!(error.line, 2);
+    }
+}
@@ -0,0 +1,254 @@
+//! A small property tester: random cases from a generator and, when one fails,
+//! a search for the smallest case that still fails.
+//!
+//! Generators are plain functions of a [`Source`] that draw whatever they need
+//! from it. Every draw is recorded, and shrinking works on that record rather
+//! than on the values: it deletes runs of draws and makes single draws smaller,
+//! then replays the generator on the result. Anything built from a `Source`
+//! therefore shrinks towards fewer items and smaller numbers with no extra code.
+//!
+//! A property fails by returning `false` or by panicking. Two variables tune a
+//! run:
+//!
+//! ```text
+//! AOC_PROP_CASES=10000   cases per property (default 256)
+//! AOC_PROP_SEED=42       seed for the cases, printed on failure (default fixed)
+//! ```
+
+use std::cell::Cell;
+use std::fmt::Debug;
+use std::ops::RangeInclusive;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+use crate::rng::Rng;
+
+const DEFAULT_CASES: u64 = 256;
+const DEFAULT_SEED: u64 = 0x5eed;
+/// Candidates tried while shrinking one failure, so a slow property still ends.
+const MAX_SHRINK_TRIES: usize = 20_000;
+
+/// Where a generator gets its randomness: fresh random draws while looking
+/// for a failure, and a fixed list of draws while shrinking one.
+pub struct Source {
+    rng: Option<Rng>,
+    replay: Vec<u64>,
+    drawn: Vec<u64>,
+}
+
+impl Source {
+    fn random(seed: u64) -> Source {
+        Source {
+            rng: Some(Rng::new(seed)),
+            replay: vec![],
+            drawn: vec![],
+        }
+    }
+
+    fn replaying(draws: Vec<u64>) -> Source {
+        Source {
+            rng: None,
+            replay: draws,
+            drawn: vec![],
+        }
+    }
+
+    /// A number in `0..=max`. Past the end of a replay every draw is 0.
+    fn draw(&mut self, max: u64) -> u64 {
+        let n = match &mut self.rng {
+            Some(rng) if max == u64::MAX => rng.next_u64(),
+            Some(rng) => rng.below(max + 1),
+            None => self.replay.get(self.drawn.len()).map_or(0, |&n| n.min(max)),
+        };
+        self.drawn.push(n);
+        n
+    }
+
+    /// A number in `range`, shrinking towards its start.
+    pub fn int<T>(&mut self, range: RangeInclusive<T>) -> T
+    where
+        T: Copy + TryFrom<i128> + TryInto<i128>,
+    {
+        let wide = |n: T| {
+            n.try_into()
+                .unwrap_or_else(|_| unreachable!("fits in i128"))
+        };
+        let (start, end) = (wide(*range.start()), wide(*range.end()));
+        assert!(start <= end, "empty range");
+        let offset = self.draw((end - start) as u64);
+        T::try_from(start + i128::from(offset)).unwrap_or_else(|_| unreachable!("in range"))
+    }
+
+    /// Shrinks towards `false`.
+    pub fn bool(&mut self) -> bool {
+        self.draw(1) == 1
+    }
+
+    /// One of `items`, shrinking towards the first.
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.int(0..=items.len() - 1)]
+    }
+
+    /// Between `len.start()` and `len.end()` items, shrinking towards fewer.
+    pub fn vec<T>(
+        &mut self,
+        len: RangeInclusive<usize>,
+        mut item: impl FnMut(&mut Source) -> T,
+    ) -> Vec<T> {
+        let mut items = vec![];
+        // A "one more" draw before each optional item, rather than a length up
+        // front, means deleting an item's draws deletes just that item.
+        while items.len() < *len.end() && (items.len() < *len.start() || self.bool()) {
+            items.push(item(self));
+        }
+        items
+    }
+
+    /// Characters from `alphabet`, shrinking towards shorter strings of its
+    /// first character.
+    pub fn string(&mut self, alphabet: &str, len: RangeInclusive<usize>) -> String {
+        let chars: Vec<char> = alphabet.chars().collect();
+        self.vec(len, |source| *source.pick(&chars))
+            .into_iter()
+            .collect()
+    }
+}
+
+thread_local! {
+    static QUIET: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Runs `f`, catching a panic without printing it. Shrinking panics thousands
+/// of times and only the last one is interesting.
+fn quietly<R>(f: impl FnOnce() -> R) -> Result<R, String> {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !QUIET.with(Cell::get) {
+                previous(info);
+            }
+        }));
+    });
+    QUIET.with(|quiet| quiet.set(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    QUIET.with(|quiet| quiet.set(false));
+    result.map_err(|payload| {
+        if let Some(message) = payload.downcast_ref::<&str>() {
+            message.to_string()
+        } else if let Some(message) = payload.downcast_ref::<String>() {
+            message.clone()
+        } else {
+            "unknown panic".to_string()
+        }
+    })
+}
+
+/// Why `value` breaks the property, if it does.
+fn failure<T>(property: &impl Fn(&T) -> bool, value: &T) -> Option<String> {
+    match quietly(|| property(value)) {
+        Ok(true) => None,
+        Ok(false) => Some("the property returned false".to_string()),
+        Err(message) => Some(format!("the property panicked: {message}")),
+    }
+}
+
+/// Shorter first, then smaller draw by draw. Shrinking only ever moves down
+/// this order, so it has to stop.
+fn is_simpler(a: &[u64], b: &[u64]) -> bool {
+    (a.len(), a) < (b.len(), b)
+}
+
+/// The simpler draws to try in place of `draws`.
+fn candidates(draws: &[u64]) -> Vec<Vec<u64>> {
+    let mut candidates = vec![];
+    for size in [8, 4, 2, 1] {
+        for start in (0..draws.len().saturating_sub(size - 1)).rev() {
+            let mut shorter = draws.to_vec();
+            shorter.drain(start..start + size);
+            candidates.push(shorter);
+        }
+    }
+    for (i, &n) in draws.iter().enumerate() {
+        for smaller in [0, n / 2, n.saturating_sub(1)] {
+            if smaller < n {
+                let mut draws = draws.to_vec();
+                draws[i] = smaller;
+                candidates.push(draws);
+            }
+        }
+    }
+    candidates
+}
+
+fn env_or(name: &str, default: u64) -> u64 {
+    std::env::var(name)
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(default)
+}
+
+/// Checks `property` on cases from `generate`, panicking with the simplest
+/// failing case it can find.
+pub fn check<T: Debug>(generate: impl Fn(&mut Source) -> T, property: impl Fn(&T) -> bool) {
+    let cases = env_or("AOC_PROP_CASES", DEFAULT_CASES);
+    let seed = env_or("AOC_PROP_SEED", DEFAULT_SEED);
+    for case in 0..cases {
+        let mut source = Source::random(seed.wrapping_add(case));
+        let value = generate(&mut source);
+        let Some(mut reason) = failure(&property, &value) else {
+            continue;
+        };
+
+        let (mut draws, mut value) = (source.drawn, value);
+        let (mut shrinks, mut tries) = (0, 0);
+        'shrinking: loop {
+            for candidate in candidates(&draws) {
+                tries += 1;
+                if tries > MAX_SHRINK_TRIES {
+                    break 'shrinking;
+                }
+                let mut source = Source::replaying(candidate);
+                // A generator may reject what it's given by panicking.
+                let Ok(smaller) = quietly(|| generate(&mut source)) else {
+                    continue;
+                };
+                if !is_simpler(&source.drawn, &draws) {
+                    continue;
+                }
+                if let Some(why) = failure(&property, &smaller) {
+                    (draws, value, reason) = (source.drawn, smaller, why);
+                    shrinks += 1;
+                    continue 'shrinking;
+                }
+            }
+            break;
+        }
+        panic!(
+            "property failed on case {case} of {cases} (AOC_PROP_SEED={seed}), \
+             shrunk {shrinks} times to\n{value:#?}\n{reason}"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn failures_shrink_to_the_smallest_case() {
+        check(|source| source.int(0..=255u8), |&n| u32::from(n) < 256);
+
+        let failed = quietly(|| {
+            check(
+                |source| source.vec(0..=20, |source| source.int(0..=1000u32)),
+                |numbers| numbers.iter().sum::<u32>() < 100,
+            )
+        });
+        let message = failed.unwrap_err();
+        assert!(
+            message.ends_with("[\n    100,\n]\nthe property returned false"),
+            "{message}"
+        );
+    }
+}
@@ -0,0 +1,429 @@
+//! Turns a traced grid into a picture: a PNG of the final state, an animated
+//! GIF of every state, or an SVG of the final state.
+//!
+//! Each character of a state is one square cell, coloured by the day's
+//! [`Palette`]. The last path the solver traced is drawn over the cells,
+//! joining each cell to the next when they share a row or a column.
+//!
+//! A day's `main` opts in with [`main`], which gives its binary a
+//! `--render <file>` option and picks the format from the file's extension.
+
+use std::fmt::Write as _;
+use std::fs::{self, File};
+use std::io::{self, BufWriter};
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use crate::trace::{self, Event, Trace};
+
+pub type Rgb = [u8; 3];
+
+/// The largest picture, in pixels along either side, that cells are scaled up to.
+const TARGET_SIZE: usize = 1200;
+const MAX_CELL: usize = 12;
+/// A long simulation is sampled down to this many GIF frames.
+const MAX_FRAMES: usize = 600;
+/// Hundredths of a second per GIF frame, and for the final one.
+const FRAME_DELAY: u16 = 4;
+const LAST_FRAME_DELAY: u16 = 300;
+
+#[derive(Debug, Clone)]
+pub struct Palette {
+    background: Rgb,
+    tiles: Vec<(char, Rgb)>,
+    path: Rgb,
+}
+
+impl Palette {
+    /// Characters without a colour of their own are drawn in `background`.
+    pub fn new(background: Rgb) -> Palette {
+        Palette {
+            background,
+            tiles: vec![],
+            path: [0xe0, 0x30, 0x30],
+        }
+    }
+
+    /// Draws every character in `chars` in `color`.
+    pub fn tiles(mut self, chars: &str, color: Rgb) -> Palette {
+        for c in chars.chars() {
+            self.tiles.retain(|&(tile, _)| tile != c);
+            self.tiles.push((c, color));
+        }
+        self
+    }
+
+    pub fn path(mut self, color: Rgb) -> Palette {
+        self.path = color;
+        self
+    }
+
+    /// Every colour, background first and the path's last.
+    fn colors(&self) -> Vec<Rgb> {
+        let mut colors = vec![self.background];
+        colors.extend(self.tiles.iter().map(|&(_, color)| color));
+        colors.push(self.path);
+        colors
+    }
+
+    fn index(&self, c: char) -> u8 {
+        self.tiles
+            .iter()
+            .position(|&(tile, _)| tile == c)
+            .map_or(0, |i| i as u8 + 1)
+    }
+
+    fn path_index(&self) -> u8 {
+        self.tiles.len() as u8 + 1
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Png,
+    Gif,
+    Svg,
+}
+
+impl Format {
+    pub fn from_path(path: &Path) -> Option<Format> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "png" => Some(Format::Png),
+            "gif" => Some(Format::Gif),
+            "svg" => Some(Format::Svg),
+            _ => None,
+        }
+    }
+}
+
+/// One state of the grid with the path drawn over it.
+struct Picture {
+    rows: Rc<Vec<Rc<str>>>,
+    path: Option<Rc<[(usize, usize)]>>,
+}
+
+impl Picture {
+    fn size(&self) -> (usize, usize) {
+        let width = self.rows.iter().map(|row| row.chars().count()).max();
+        (width.unwrap_or(0), self.rows.len())
+    }
+
+    /// Consecutive cells of the path that share a row or a column.
+    fn segments(&self) -> Vec<((usize, usize), (usize, usize))> {
+        let Some(path) = &self.path else {
+            return vec![];
+        };
+        path.windows(2)
+            .filter(|pair| pair[0].0 == pair[1].0 || pair[0].1 == pair[1].1)
+            .map(|pair| (pair[0], pair[1]))
+            .collect()
+    }
+}
+
+/// Pixels per cell for a grid of this many cells.
+fn cell_size((width, height): (usize, usize)) -> usize {
+    (TARGET_SIZE / width.max(height).max(1)).clamp(1, MAX_CELL)
+}
+
+/// Draws `picture` as palette indices, `cell` pixels to a cell.
+fn raster(picture: &Picture, palette: &Palette, size: (usize, usize), cell: usize) -> Vec<u8> {
+    let width = size.0 * cell;
+    let mut pixels = vec![0; width * size.1 * cell];
+    let mut fill = |x0: usize, y0: usize, w: usize, h: usize, index: u8| {
+        for y in y0..(y0 + h).min(size.1 * cell) {
+            let row = &mut pixels[y * width..(y + 1) * width];
+            for pixel in &mut row[x0.min(width)..(x0 + w).min(width)] {
+                *pixel = index;
+            }
+        }
+    };
+    for (y, row) in picture.rows.iter().enumerate() {
+        for (x, c) in row.chars().enumerate() {
+            fill(x * cell, y * cell, cell, cell, palette.index(c));
+        }
+    }
+
+    let thickness = (cell / 3).max(1);
+    let offset = (cell - thickness) / 2;
+    let path = palette.path_index();
+    for ((x0, y0), (x1, y1)) in picture.segments() {
+        let (left, right) = (x0.min(x1), x0.max(x1));
+        let (top, bottom) = (y0.min(y1), y0.max(y1));
+        fill(
+            left * cell + offset,
+            top * cell + offset,
+            (right - left) * cell + thickness,
+            (bottom - top) * cell + thickness,
+            path,
+        );
+    }
+    for &(x, y) in picture.path.iter().flat_map(|path| path.iter()) {
+        fill(
+            x * cell + offset,
+            y * cell + offset,
+            thickness,
+            thickness,
+            path,
+        );
+    }
+    pixels
+}
+
+fn hex([r, g, b]: Rgb) -> String {
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+
+fn svg(picture: &Picture, palette: &Palette) -> String {
+    let (width, height) = picture.size();
+    let cell = cell_size((width, height));
+    let mut out = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" \
+         viewBox=\"0 0 {width} {height}\" shape-rendering=\"crispEdges\">\n\
+         <rect width=\"{width}\" height=\"{height}\" fill=\"{}\"/>\n",
+        width * cell,
+        height * cell,
+        hex(palette.background)
+    );
+    let colors = palette.colors();
+    for (y, row) in picture.rows.iter().enumerate() {
+        // One rectangle per run of same-coloured cells.
+        let indices: Vec<u8> = row.chars().map(|c| palette.index(c)).collect();
+        let mut x = 0;
+        for run in indices.chunk_by(|a, b| a == b) {
+            if run[0] != 0 {
+                let _ = writeln!(
+                    out,
+                    "<rect x=\"{x}\" y=\"{y}\" width=\"{}\" height=\"1\" fill=\"{}\"/>",
+                    run.len(),
+                    hex(colors[run[0] as usize])
+                );
+            }
+            x += run.len();
+        }
+    }
+
+    if let Some(path) = &picture.path {
+        // A new line wherever the path jumps rather than running straight on.
+        let mut lines: Vec<Vec<(usize, usize)>> = vec![];
+        for (i, &cell) in path.iter().enumerate() {
+            let joined = i > 0 && {
+                let prev = path[i - 1];
+                prev.0 == cell.0 || prev.1 == cell.1
+            };
+            match lines.last_mut() {
+                Some(line) if joined => line.push(cell),
+                _ => lines.push(vec![cell]),
+            }
+        }
+        for line in lines {
+            let points: Vec<String> = line
+                .iter()
+                .map(|(x, y)| format!("{}.5,{}.5", x, y))
+                .collect();
+            let _ = writeln!(
+                out,
+                "<polyline points=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"0.35\" \
+                 stroke-linecap=\"round\" stroke-linejoin=\"round\"/>",
+                points.join(" "),
+                hex(palette.path)
+            );
+        }
+    }
+    out.push_str("</svg>\n");
+    out
+}
+
+fn png(picture: &Picture, palette: &Palette, out: &Path) -> io::Result<()> {
+    let size = picture.size();
+    let cell = cell_size(size);
+    let colors = palette.colors();
+    let rgb: Vec<u8> = raster(picture, palette, size, cell)
+        .into_iter()
+        .flat_map(|index| colors[index as usize])
+        .collect();
+    let mut encoder = png::Encoder::new(
+        BufWriter::new(File::create(out)?),
+        (size.0 * cell) as u32,
+        (size.1 * cell) as u32,
+    );
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header().map_err(io::Error::other)?;
+    writer.write_image_data(&rgb).map_err(io::Error::other)
+}
+
+fn gif(pictures: &[Picture], palette: &Palette, out: &Path) -> io::Result<()> {
+    // Every frame is drawn at the size of the largest.
+    let size = pictures.iter().fold((0, 0), |(w, h), picture| {
+        let (pw, ph) = picture.size();
+        (w.max(pw), h.max(ph))
+    });
+    let cell = cell_size(size);
+    let (width, height) = ((size.0 * cell) as u16, (size.1 * cell) as u16);
+    let colors: Vec<u8> = palette.colors().concat();
+    let mut encoder = gif::Encoder::new(BufWriter::new(File::create(out)?), width, height, &colors)
+        .map_err(io::Error::other)?;
+    encoder
+        .set_repeat(gif::Repeat::Infinite)
+        .map_err(io::Error::other)?;
+    for (i, picture) in pictures.iter().enumerate() {
+        let mut frame = gif::Frame::from_indexed_pixels(
+            width,
+            height,
+            raster(picture, palette, size, cell),
+            None,
+        );
+        frame.delay = if i + 1 == pictures.len() {
+            LAST_FRAME_DELAY
+        } else {
+            FRAME_DELAY
+        };
+        encoder.write_frame(&frame).map_err(io::Error::other)?;
+    }
+    Ok(())
+}
+
+/// Renders the states of a trace to `out`, in the format its extension names.
+pub fn render(trace: &str, palette: &Palette, out: &Path) -> io::Result<()> {
+    let format = Format::from_path(out).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("{} should end in .png, .gif or .svg", out.display()),
+        )
+    })?;
+    let invalid = |e| io::Error::new(io::ErrorKind::InvalidData, format!("bad trace: {e}"));
+
+    let mut states = 0;
+    Trace::replay(trace, |step| {
+        states += usize::from(matches!(step.event, Event::State(_)))
+    })
+    .map_err(invalid)?;
+    if states == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "nothing was traced to render",
+        ));
+    }
+    // Only the GIF wants more than the last state.
+    let every = match format {
+        Format::Gif => states.div_ceil(MAX_FRAMES),
+        Format::Png | Format::Svg => states,
+    };
+
+    let mut pictures = vec![];
+    let mut path: Option<Rc<[(usize, usize)]>> = None;
+    let mut seen = 0;
+    Trace::replay(trace, |step| match step.event {
+        Event::Path(cells) => path = Some(cells.into()),
+        Event::State(_) => {
+            seen += 1;
+            if seen % every == 0 || seen == states {
+                pictures.push(Picture {
+                    rows: step.rows,
+                    path: path.clone(),
+                });
+            }
+        }
+        _ => (),
+    })
+    .map_err(invalid)?;
+    // A path traced after the last state belongs on it too.
+    if let Some(last) = pictures.last_mut() {
+        last.path = path;
+    }
+
+    let picture = pictures.last().expect("at least one state");
+    match format {
+        Format::Png => png(picture, palette, out),
+        Format::Svg => fs::write(out, svg(picture, palette)),
+        Format::Gif => gif(&pictures, palette, out),
+    }
+}
+
+/// Runs a day's `main`, and with `--render <file>` on the command line also
+/// traces it and renders the trace to the file.
+pub fn main(palette: &Palette, solve: impl FnOnce()) {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let out = match &args[..] {
+        [] => None,
+        [flag, file] if flag == "--render" => Some(PathBuf::from(file)),
+        _ => {
+            eprintln!("usage: [--render <file>.png|gif|svg]");
+            std::process::exit(2);
+        }
+    };
+    let Some(out) = out else {
+        solve();
+        return;
+    };
+
+    let trace = std::env::temp_dir().join(format!("aoc-render-{}.trace", std::process::id()));
+    // Render whatever was traced before a panic too, then carry on panicking.
+    let solved = trace::tracing_to(Some(&trace), || {
+        panic::catch_unwind(AssertUnwindSafe(solve))
+    });
+    let rendered = fs::read_to_string(&trace).and_then(|text| render(&text, palette, &out));
+    let _ = fs::remove_file(&trace);
+    match rendered {
+        Ok(()) => eprintln!("rendered to {}", out.display()),
+        Err(e) => {
+            eprintln!("render: {e}");
+            std::process::exit(1);
+        }
+    }
+    if let Err(payload) = solved {
+        panic::resume_unwind(payload);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::trace::Tracer;
+
+    #[test]
+    fn pictures_in_every_format() {
+        let dir = std::env::temp_dir();
+        let trace = dir.join(format!("aoc-render-test-{}.trace", std::process::id()));
+        let mut tracer = Tracer::create(&trace).unwrap();
+        tracer.state("#.\n..");
+        tracer.state("#.\n.O");
+        tracer.path([(0, 1), (1, 1), (1, 0)]);
+        drop(tracer);
+        let text = fs::read_to_string(&trace).unwrap();
+        fs::remove_file(&trace).unwrap();
+
+        let palette = Palette::new([0, 0, 0])
+            .tiles("#", [255, 255, 255])
+            .tiles("O", [0, 0, 255])
+            .path([255, 0, 0]);
+        let picture = Picture {
+            rows: Rc::new(vec!["#.".into(), ".O".into()]),
+            path: Some(vec![(0, 1), (1, 1)].into()),
+        };
+        let pixels = raster(&picture, &palette, (2, 2), 3);
+        assert_eq!(&pixels[..6], &[1, 1, 1, 0, 0, 0]);
+        // The path runs along the middle of the bottom row, over the rock.
+        assert_eq!(&pixels[18..24], &[0, 0, 0, 2, 2, 2]);
+        assert_eq!(&pixels[24..30], &[0, 3, 3, 3, 3, 2]);
+
+        let svg = svg(&picture, &palette);
+        assert!(svg.contains("<rect x=\"0\" y=\"0\" width=\"1\" height=\"1\" fill=\"#ffffff\"/>"));
+        assert!(svg.contains("points=\"0.5,1.5 1.5,1.5\""));
+
+        for (name, magic) in [
+            ("png", &b"\x89PNG"[..]),
+            ("gif", b"GIF89a"),
+            ("svg", b"<svg"),
+        ] {
+            let out = dir.join(format!("aoc-render-test-{}.{name}", std::process::id()));
+            render(&text, &palette, &out).unwrap();
+            assert!(fs::read(&out).unwrap().starts_with(magic), "{name}");
+            fs::remove_file(&out).unwrap();
+        }
+        assert!(render("aoc-trace 1\n", &palette, &dir.join("x.png")).is_err());
+        assert!(render(&text, &palette, &dir.join("x.bmp")).is_err());
+    }
+}
@@ -0,0 +1,419 @@
+//! Record a simulation step by step, then replay it with the `trace` viewer.
+//!
+//! A solver holds a [`Tracer`] and reports what it does: `state` with anything
+//! that renders as a grid of text, `push`/`pop` for its work queue, `path` for
+//! a route to draw over the grid and `note` for anything else. Tracing is off
+//! unless `AOC_TRACE` names a file, and an off tracer never renders anything,
+//! so the calls can stay in the solvers. A caller running a solver can pick
+//! the file with [`tracing_to`] instead, which leaves the environment alone.
+//!
+//! The file is plain text, one event per line with tab-separated fields (shown
+//! as spaces here), and a state only lists the rows that changed since the
+//! previous one:
+//!
+//! ```text
+//! aoc-trace 1
+//! state  3  0  ..#  2  #..
+//! push  (1, 0, Right)
+//! pop  (1, 0, Right)
+//! path  0,0  1,0  1,1
+//! note  north
+//! ```
+
+use std::cell::RefCell;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use crate::parse::{self, Scanner};
+
+const HEADER: &str = "aoc-trace";
+const VERSION: u32 = 1;
+
+thread_local! {
+    /// Set by [`tracing_to`]: the file to trace to, or `Some(None)` for none.
+    static TARGET: RefCell<Option<Option<PathBuf>>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` with every [`Tracer::from_env`] on this thread tracing to `path`,
+/// or not tracing for `None`, whatever `AOC_TRACE` says.
+pub fn tracing_to<T>(path: Option<&Path>, f: impl FnOnce() -> T) -> T {
+    struct Restore(Option<Option<PathBuf>>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            TARGET.with(|target| *target.borrow_mut() = self.0.take());
+        }
+    }
+    let previous = TARGET.with(|target| target.replace(Some(path.map(Path::to_path_buf))));
+    // Put back even when `f` panics.
+    let _restore = Restore(previous);
+    f()
+}
+
+pub struct Tracer {
+    out: Option<Box<dyn Write>>,
+    rows: Vec<String>,
+}
+
+impl fmt::Debug for Tracer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Tracer").field("on", &self.is_on()).finish()
+    }
+}
+
+impl Tracer {
+    /// A tracer that records nothing.
+    pub fn off() -> Tracer {
+        Tracer {
+            out: None,
+            rows: vec![],
+        }
+    }
+
+    /// Writes the trace to `out`.
+    pub fn new(out: impl Write + 'static) -> Tracer {
+        let mut tracer = Tracer {
+            out: Some(Box::new(out)),
+            rows: vec![],
+        };
+        tracer.write(format!("{HEADER} {VERSION}"));
+        tracer
+    }
+
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Tracer> {
+        Ok(Tracer::new(BufWriter::new(File::create(path)?)))
+    }
+
+    /// Traces to the file given to [`tracing_to`] on this thread, else the
+    /// one named by `AOC_TRACE`, or not at all.
+    pub fn from_env() -> Tracer {
+        let path = match TARGET.with(|target| target.borrow().clone()) {
+            Some(target) => target.map(PathBuf::into_os_string),
+            None => std::env::var_os("AOC_TRACE"),
+        };
+        let Some(path) = path else {
+            return Tracer::off();
+        };
+        Tracer::create(&path).unwrap_or_else(|e| {
+            eprintln!("trace: can't create {}: {e}", path.to_string_lossy());
+            Tracer::off()
+        })
+    }
+
+    pub fn is_on(&self) -> bool {
+        self.out.is_some()
+    }
+
+    fn write(&mut self, line: String) {
+        if let Some(out) = &mut self.out {
+            if let Err(e) = writeln!(out, "{line}") {
+                // A broken trace shouldn't take the solver down with it.
+                eprintln!("trace: {e}, no longer tracing");
+                self.out = None;
+            }
+        }
+    }
+
+    /// Records the rendered state, keeping only the rows that changed.
+    pub fn state(&mut self, state: impl fmt::Display) {
+        if !self.is_on() {
+            return;
+        }
+        let text = state.to_string();
+        let rows: Vec<String> = text.lines().map(String::from).collect();
+        let mut line = format!("state\t{}", rows.len());
+        for (i, row) in rows.iter().enumerate() {
+            if self.rows.get(i) != Some(row) {
+                line += &format!("\t{i}\t{}", escape(row));
+            }
+        }
+        self.rows = rows;
+        self.write(line);
+    }
+
+    /// Records an item going onto the work queue.
+    pub fn push(&mut self, item: impl fmt::Debug) {
+        if self.is_on() {
+            self.write(format!("push\t{}", escape(&format!("{item:?}"))));
+        }
+    }
+
+    /// Records an item coming off the work queue.
+    pub fn pop(&mut self, item: impl fmt::Debug) {
+        if self.is_on() {
+            self.write(format!("pop\t{}", escape(&format!("{item:?}"))));
+        }
+    }
+
+    /// Records a route through the grid, as (column, row) positions in the
+    /// rendered state, in the order it's walked.
+    pub fn path(&mut self, cells: impl IntoIterator<Item = (usize, usize)>) {
+        if self.is_on() {
+            let mut line = String::from("path");
+            for (x, y) in cells {
+                line += &format!("\t{x},{y}");
+            }
+            self.write(line);
+        }
+    }
+
+    pub fn note(&mut self, note: impl fmt::Display) {
+        if self.is_on() {
+            self.write(format!("note\t{}", escape(&note.to_string())));
+        }
+    }
+}
+
+fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\t' => out.push_str("\\t"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn unescape(field: Scanner) -> parse::Result<String> {
+    let mut out = String::new();
+    let mut chars = field;
+    while let Some(c) = chars.next_char() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next_char() {
+            Some('\\') => out.push('\\'),
+            Some('t') => out.push('\t'),
+            Some('n') => out.push('\n'),
+            Some('r') => out.push('\r'),
+            _ => return Err(chars.error("bad escape")),
+        }
+    }
+    Ok(out)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    /// The state was redrawn; these rows changed.
+    State(Vec<usize>),
+    Push(String),
+    Pop(String),
+    Path(Vec<(usize, usize)>),
+    Note(String),
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Event::State(changed) => write!(f, "state, {} rows changed", changed.len()),
+            Event::Push(item) => write!(f, "push {item}"),
+            Event::Pop(item) => write!(f, "pop {item}"),
+            Event::Path(cells) => write!(f, "path through {} cells", cells.len()),
+            Event::Note(note) => write!(f, "{note}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Step {
+    pub event: Event,
+    /// The last state drawn at or before this step.
+    pub rows: Rc<Vec<Rc<str>>>,
+    /// Pushes minus pops so far.
+    pub queue_len: usize,
+}
+
+/// A trace read back in, with the state rebuilt at every step.
+#[derive(Debug, Clone, Default)]
+pub struct Trace {
+    pub steps: Vec<Step>,
+}
+
+impl Trace {
+    pub fn parse(input: &str) -> parse::Result<Trace> {
+        let mut steps = vec![];
+        Trace::replay(input, |step| steps.push(step))?;
+        Ok(Trace { steps })
+    }
+
+    /// Hands each step to `visit` as it's read, without holding on to them,
+    /// for traces too long to keep every step of.
+    pub fn replay(input: &str, mut visit: impl FnMut(Step)) -> parse::Result<()> {
+        let mut lines = Scanner::new(input).lines();
+        let mut header = lines
+            .next()
+            .ok_or_else(|| Scanner::new(input).error("empty trace"))?;
+        header.tag(HEADER)?;
+        let version = header.int::<u32>()?;
+        if version != VERSION {
+            return Err(header.error(format!("unsupported trace version {version}")));
+        }
+        header.finish()?;
+
+        let mut rows: Rc<Vec<Rc<str>>> = Rc::default();
+        let mut queue_len: usize = 0;
+        for line in lines.filter(|line| !line.is_empty()) {
+            let mut fields = line.split('\t');
+            let mut kind = fields.next().unwrap_or(line);
+            let name = kind.word()?;
+            let event = match name {
+                "state" => {
+                    let mut height = fields
+                        .next()
+                        .ok_or_else(|| line.error("expected a height"))?;
+                    let height = height.int::<usize>()?;
+                    let mut next = rows.as_ref().clone();
+                    next.resize(height, Rc::from(""));
+                    let mut changed = vec![];
+                    while let Some(mut index) = fields.next() {
+                        let row = index.int::<usize>()?;
+                        if row >= height {
+                            return Err(
+                                index.error(format!("row {row} is past the height {height}"))
+                            );
+                        }
+                        let text = fields
+                            .next()
+                            .ok_or_else(|| index.error("expected the row's text"))?;
+                        next[row] = Rc::from(unescape(text)?);
+                        changed.push(row);
+                    }
+                    rows = Rc::new(next);
+                    Event::State(changed)
+                }
+                "path" => {
+                    let mut cells = vec![];
+                    for mut cell in fields {
+                        let x = cell.int::<usize>()?;
+                        cell.tag(",")?;
+                        let y = cell.int::<usize>()?;
+                        cell.finish()?;
+                        cells.push((x, y));
+                    }
+                    Event::Path(cells)
+                }
+                "push" | "pop" | "note" => {
+                    let text = match fields.next() {
+                        Some(field) => unescape(field)?,
+                        None => String::new(),
+                    };
+                    match name {
+                        "push" => {
+                            queue_len += 1;
+                            Event::Push(text)
+                        }
+                        "pop" => {
+                            queue_len = queue_len.saturating_sub(1);
+                            Event::Pop(text)
+                        }
+                        _ => Event::Note(text),
+                    }
+                }
+                other => return Err(line.error(format!("unknown event `{other}`"))),
+            };
+            visit(Step {
+                event,
+                rows: Rc::clone(&rows),
+                queue_len,
+            });
+        }
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// What is on the queue after `step`, oldest first.
+    pub fn queue_at(&self, step: usize) -> Vec<&str> {
+        let mut queue: Vec<&str> = vec![];
+        for step in &self.steps[..=step.min(self.steps.len().saturating_sub(1))] {
+            match &step.event {
+                Event::Push(item) => queue.push(item),
+                Event::Pop(item) => {
+                    // Whether it's a stack or a queue, take the newest copy.
+                    if let Some(i) = queue.iter().rposition(|queued| queued == item) {
+                        queue.remove(i);
+                    }
+                }
+                _ => (),
+            }
+        }
+        queue
+    }
+
+    /// The last path recorded at or before `step`.
+    pub fn path_at(&self, step: usize) -> Option<&[(usize, usize)]> {
+        self.steps[..=step.min(self.steps.len().saturating_sub(1))]
+            .iter()
+            .rev()
+            .find_map(|step| match &step.event {
+                Event::Path(cells) => Some(cells.as_slice()),
+                _ => None,
+            })
+    }
+
+    /// The next step after `from` that redrew the state.
+    pub fn next_state(&self, from: usize) -> Option<usize> {
+        (from + 1..self.steps.len()).find(|&i| matches!(self.steps[i].event, Event::State(_)))
+    }
+
+    /// The last step before `from` that redrew the state.
+    pub fn prev_state(&self, from: usize) -> Option<usize> {
+        (0..from.min(self.steps.len()))
+            .rev()
+            .find(|&i| matches!(self.steps[i].event, Event::State(_)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_and_replay() {
+        let path = std::env::temp_dir().join(format!("aoc-trace-{}.txt", std::process::id()));
+        let mut tracer = tracing_to(Some(&path), Tracer::from_env);
+        assert!(tracer.is_on());
+        assert!(!tracing_to(None, Tracer::from_env).is_on());
+        tracer.state("#..\n...");
+        tracer.push((1, 0));
+        tracer.push("tab\there");
+        tracer.pop("tab\there");
+        tracer.state("##.\n...\n..#");
+        tracer.note("north");
+        tracer.path([(0, 0), (1, 0)]);
+        drop(tracer);
+
+        let text = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(text.contains("state\t3\t0\t##.\t2\t..#\n"));
+
+        let trace = Trace::parse(&text).unwrap();
+        assert_eq!(trace.len(), 7);
+        assert_eq!(
+            trace.steps[3].event,
+            Event::Pop("\"tab\\there\"".to_string())
+        );
+        assert_eq!(trace.steps[3].queue_len, 1);
+        assert_eq!(trace.queue_at(3), vec!["(1, 0)"]);
+        assert_eq!(trace.steps[4].event, Event::State(vec![0, 2]));
+        let rows: Vec<&str> = trace.steps[5].rows.iter().map(|row| &**row).collect();
+        assert_eq!(rows, vec!["##.", "...", "..#"]);
+        assert_eq!(trace.prev_state(4), Some(0));
+        assert_eq!(trace.next_state(0), Some(4));
+        assert_eq!(trace.path_at(5), None);
+        assert_eq!(trace.path_at(6), Some(&[(0, 0), (1, 0)][..]));
+    }
+}
@@ -0,0 +1,156 @@
+//! A multi-pattern matcher (Aho–Corasick) for finding every digit in a line,
+//! spelled out or not, in one pass.
+//!
+//! The patterns go into a trie whose states are the prefixes of the patterns.
+//! Each state also knows the longest proper suffix of itself that is another
+//! state, so when the next byte doesn't extend the current prefix the scan
+//! falls back to that suffix rather than starting over. That is what keeps
+//! overlapping words like `eightwo` and `twone` both visible: after `eight`
+//! the state is already `t`, ready to go on to `two`. Here the fallbacks are
+//! folded into a full transition table, so each byte is one lookup.
+
+/// A pattern found in a line, at bytes `start..end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Match {
+    pub start: usize,
+    pub end: usize,
+    pub value: u32,
+}
+
+pub struct Automaton {
+    /// The state after each byte, for every state.
+    next: Vec<[u32; 256]>,
+    /// The patterns ending at each state, itself or any of its suffixes.
+    ends: Vec<Vec<usize>>,
+    /// Each pattern's length and value.
+    patterns: Vec<(usize, u32)>,
+    /// The length of the longest pattern.
+    longest: usize,
+}
+
+impl Automaton {
+    pub fn new<P: AsRef<[u8]>>(patterns: impl IntoIterator<Item = (P, u32)>) -> Automaton {
+        let mut trie = Automaton {
+            next: vec![[0; 256]],
+            ends: vec![vec![]],
+            patterns: vec![],
+            longest: 0,
+        };
+        // The trie, with 0 standing for "no edge" until the fallbacks are in.
+        for (word, value) in patterns {
+            let word = word.as_ref();
+            let mut state = 0;
+            for &byte in word {
+                state = match trie.next[state][byte as usize] {
+                    0 => {
+                        trie.next.push([0; 256]);
+                        trie.ends.push(vec![]);
+                        let new = trie.next.len() - 1;
+                        trie.next[state][byte as usize] = new as u32;
+                        new
+                    }
+                    next => next as usize,
+                };
+            }
+            trie.ends[state].push(trie.patterns.len());
+            trie.patterns.push((word.len(), value));
+            trie.longest = trie.longest.max(word.len());
+        }
+
+        // Breadth first, so a state's fallback is finished before its children's.
+        let mut fallback = vec![0; trie.next.len()];
+        let mut queue: std::collections::VecDeque<usize> = trie.next[0]
+            .iter()
+            .filter(|&&child| child != 0)
+            .map(|&child| child as usize)
+            .collect();
+        while let Some(state) = queue.pop_front() {
+            let ends = trie.ends[fallback[state]].clone();
+            trie.ends[state].extend(ends);
+            for byte in 0..256 {
+                let child = trie.next[state][byte] as usize;
+                let on_fallback = trie.next[fallback[state]][byte];
+                if child == 0 {
+                    trie.next[state][byte] = on_fallback;
+                } else {
+                    fallback[child] = on_fallback as usize;
+                    queue.push_back(child);
+                }
+            }
+        }
+        trie
+    }
+
+    /// Every match in `text`, in order of where they end, shortest first.
+    pub fn matches<'a, I>(&'a self, text: I) -> impl Iterator<Item = Match> + 'a
+    where
+        I: IntoIterator<Item = u8>,
+        I::IntoIter: 'a,
+    {
+        let mut state = 0;
+        text.into_iter().enumerate().flat_map(move |(i, byte)| {
+            state = self.next[state][byte as usize] as usize;
+            let end = i + 1;
+            self.ends[state].iter().rev().map(move |&pattern| {
+                let (len, value) = self.patterns[pattern];
+                Match {
+                    start: end - len,
+                    end,
+                    value,
+                }
+            })
+        })
+    }
+
+    /// The match that starts first in `text`, the longest one if several do.
+    ///
+    /// Matches are found by where they end, so a later one can still start
+    /// earlier; but none can start before the best so far once the scan is a
+    /// longest pattern past it, and the rest of `text` is never read.
+    pub fn leftmost(&self, text: impl IntoIterator<Item = u8>) -> Option<Match> {
+        let mut best: Option<Match> = None;
+        for m in self.matches(text) {
+            if best.is_some_and(|best| m.end > best.start + self.longest) {
+                break;
+            }
+            let earlier =
+                |best: Match| m.start < best.start || (m.start == best.start && m.end > best.end);
+            if best.is_none_or(earlier) {
+                best = Some(m);
+            }
+        }
+        best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::prop;
+
+    #[test]
+    fn finds_what_a_naive_scan_finds() {
+        let words = ["a", "ab", "bab", "b", "abba"];
+        let automaton = Automaton::new(words.iter().copied().zip(0..));
+        prop::check(
+            |source| source.string("abx", 0..=12),
+            |text| {
+                let mut naive = vec![];
+                for end in 1..=text.len() {
+                    for start in (0..end).rev() {
+                        if let Some(value) = words.iter().position(|w| *w == &text[start..end]) {
+                            naive.push(Match {
+                                start,
+                                end,
+                                value: value as u32,
+                            });
+                        }
+                    }
+                }
+                let leftmost = naive.iter().copied().min_by_key(|m| (m.start, !m.end));
+                automaton.matches(text.bytes()).collect::<Vec<_>>() == naive
+                    && automaton.leftmost(text.bytes()) == leftmost
+            },
+        );
+    }
+}
@@ -0,0 +1,78 @@
+//! Times part 2's digit scanner against the substring scan it replaced, on
+//! generated lines long enough to show how each grows with the line.
+//!
+//! cargo run --release --bin bench
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use aoc::rng::Rng;
+use day1::vocabulary::Vocabulary;
+
+const NAMES: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// The old scan: every substring from every position checked against the
+/// names, finding each position with `chars().nth`.
+fn substring_value(s: &str) -> u32 {
+    let mut first = None;
+    let mut last = 0;
+    for i in 0..s.len() {
+        let digit = s.chars().nth(i).and_then(|c| c.to_digit(10)).unwrap_or(0);
+        if digit > 0 {
+            first = first.or(Some(digit));
+            last = digit;
+            continue;
+        }
+        for j in i + 1..=s.len() {
+            if let Some(n) = NAMES.iter().position(|&name| name == &s[i..j]) {
+                let digit = n as u32 + 1;
+                first = first.or(Some(digit));
+                last = digit;
+            }
+        }
+    }
+    first.map_or(0, |first| first * 10 + last)
+}
+
+/// Lines of `length` bytes, mostly letters from the names so they keep
+/// nearly spelling them, with the odd digit.
+fn lines(rng: &mut Rng, count: usize, length: usize) -> Vec<String> {
+    let letters = b"efghinorstuvwx";
+    (0..count)
+        .map(|_| {
+            (0..length)
+                .map(|_| match rng.below(40) {
+                    0 => char::from(b'1' + rng.below(9) as u8),
+                    _ => char::from(letters[rng.below(letters.len() as u64) as usize]),
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn time(lines: &[String], value: impl Fn(&str) -> u32) -> (u32, Duration) {
+    let start = Instant::now();
+    let sum = lines.iter().map(|line| value(black_box(line))).sum();
+    (sum, start.elapsed())
+}
+
+fn main() {
+    let digits = Vocabulary::english().scanner();
+    let mut rng = Rng::new(1);
+    println!(
+        "{:>7} {:>6} {:>12} {:>12} {:>8}",
+        "length", "lines", "substrings", "automata", "faster"
+    );
+    for (length, count) in [(50, 20_000), (500, 2_000), (2_000, 200), (8_000, 20)] {
+        let lines = lines(&mut rng, count, length);
+        let (old_sum, old) = time(&lines, substring_value);
+        let (sum, new) = time(&lines, |line| digits.value(line));
+        assert_eq!(sum, old_sum, "the scans disagree on lines of {length}");
+        println!(
+            "{length:>7} {count:>6} {old:>12.2?} {new:>12.2?} {:>7.0}x",
+            old.as_secs_f64() / new.as_secs_f64()
+        );
+    }
+}
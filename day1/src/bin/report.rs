@@ -0,0 +1,54 @@
+//! Lists where each line's calibration value comes from, flagging the lines
+//! the sum hides.
+//!
+//! cargo run --bin report -- [--part 1|2] [--vocabulary <file>] [--json] [--flagged] [<input>]
+//!
+//! Part 2 by default, reading `src/bin/input1.txt` unless given a file.
+//! `--flagged` lists only the lines with something to flag.
+
+use std::path::Path;
+
+use aoc::args::{fail, read, Args};
+use day1::report;
+use day1::vocabulary::Vocabulary;
+
+const USAGE: &str = "usage: [--part 1|2] [--vocabulary <file>] [--json] [--flagged] [<input>]";
+
+fn main() {
+    let mut part = 2;
+    let mut vocabulary = None;
+    let mut json = false;
+    let mut flagged = false;
+    let mut input = None;
+    let mut args = Args::new(USAGE);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => match args.next().as_deref() {
+                Some("1") => part = 1,
+                Some("2") => part = 2,
+                _ => args.usage(),
+            },
+            "--vocabulary" => {
+                let file = args.value();
+                let loaded = Vocabulary::load(Path::new(&file)).unwrap_or_else(|e| fail(&e));
+                vocabulary = Some(loaded);
+            }
+            "--json" => json = true,
+            "--flagged" => flagged = true,
+            file if !file.starts_with("--") && input.is_none() => input = Some(read(file)),
+            _ => args.usage(),
+        }
+    }
+
+    let vocabulary = vocabulary.unwrap_or_else(|| match part {
+        1 => Vocabulary::numerals(),
+        _ => Vocabulary::english(),
+    });
+    let input = input.unwrap_or_else(|| include_str!("./input1.txt").to_string());
+    let lines = report::report(&input, &vocabulary.scanner());
+    if json {
+        print!("{}", report::json(&lines, flagged));
+    } else {
+        print!("{}", report::text(&lines, flagged));
+    }
+}
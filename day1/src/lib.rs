@@ -0,0 +1,7 @@
+//! Day 1: Trebuchet?!
+
+pub mod automaton;
+pub mod part1;
+pub mod part2;
+pub mod report;
+pub mod vocabulary;
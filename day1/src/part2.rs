@@ -0,0 +1,74 @@
+use aoc::input::normalize;
+use aoc::parse::Scanner;
+
+use crate::vocabulary::Vocabulary;
+
+/**
+ * Your calculation isn't quite right. It looks like some of the digits are actually spelled out with letters:
+ * one, two, three, four, five, six, seven, eight, and nine also count as valid "digits".
+ *
+ * Equipped with this new information, you now need to find the real first and last digit on each line. For example:
+ *
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+
+ * In this example, the calibration values are 29, 83, 13, 24, 42, 14, and 76. Adding these together produces 281.
+*/
+pub fn part2(input: &str) -> String {
+    part2_with(input, &Vocabulary::english())
+}
+
+/// Part 2 with the digits spelled out in `vocabulary`'s words.
+pub fn part2_with(input: &str, vocabulary: &Vocabulary) -> String {
+    let input = normalize(input);
+    let str_numbers = str_get_with_digits(&input, vocabulary);
+
+    // println!("numbers: {}", str_numbers.len());
+
+    // A line is worth up to 109989 with a custom vocabulary, so a long
+    // document can add up past a `u32`.
+    let mut sum: u64 = 0;
+    for num_string in str_numbers {
+        sum += u64::from(num_string);
+    }
+
+    return sum.to_string();
+}
+
+fn str_get_with_digits(s: &str, vocabulary: &Vocabulary) -> Vec<u32> {
+    let digits = vocabulary.scanner();
+    Scanner::new(s)
+        .lines()
+        .map(|line| digits.value(line.rest()))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{part2, part2_with};
+    use crate::vocabulary::Vocabulary;
+    use aoc::input::example;
+
+    #[test]
+    fn it_works() {
+        let result = part2(&example(
+            "two1nine
+            eightwothree
+            abcone2threexyz
+            xtwone3four
+            4nineeightseven2
+            zoneight234
+            7pqrstsixteen",
+        ));
+        assert_eq!(result, "281");
+
+        let big = Vocabulary::parse("big 9999").unwrap();
+        let lines = vec!["big"; 40_000].join("\n");
+        assert_eq!(part2_with(&lines, &big), (109_989_u64 * 40_000).to_string());
+    }
+}
@@ -0,0 +1,335 @@
+//! A line-by-line account of a calibration document: the tokens each value
+//! came from, and the lines worth a second look.
+//!
+//! Both parts count a line without digits as 0, so a garbled line just drops
+//! out of the sum. The report flags those, lines whose first and last digit
+//! are one and the same, and lines where matches share letters, as `two` and
+//! `one` do in `twone`, since that is where readings of a line differ.
+
+use std::fmt::Write;
+
+use aoc::input::normalize;
+use aoc::parse::Scanner;
+
+use crate::automaton::Match;
+use crate::vocabulary::DigitScanner;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    Digit,
+    Word,
+}
+
+/// A match, at bytes `start..end` of its line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub text: String,
+    pub start: usize,
+    pub end: usize,
+    pub value: u32,
+    pub kind: Kind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Flag {
+    /// Nothing matched, so the line counts as 0.
+    NoDigits,
+    /// The first match is also the last.
+    SingleDigit,
+    /// Matches sharing bytes, `start..end` covering all of them.
+    Overlap {
+        start: usize,
+        end: usize,
+        text: String,
+    },
+}
+
+impl Flag {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Flag::NoDigits => "no-digits",
+            Flag::SingleDigit => "single-digit",
+            Flag::Overlap { .. } => "overlap",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Line {
+    /// 1-based.
+    pub number: usize,
+    pub text: String,
+    pub first: Option<Token>,
+    pub last: Option<Token>,
+    pub value: u32,
+    pub flags: Vec<Flag>,
+}
+
+fn token(line: &str, m: Match) -> Token {
+    let text = &line[m.start..m.end];
+    let kind = if text.bytes().all(|b| b.is_ascii_digit()) {
+        Kind::Digit
+    } else {
+        Kind::Word
+    };
+    Token {
+        text: text.to_string(),
+        start: m.start,
+        end: m.end,
+        value: m.value,
+        kind,
+    }
+}
+
+/// Runs of matches in `line` that share bytes, as one flag per run.
+fn overlaps(line: &str, digits: &DigitScanner) -> Vec<Flag> {
+    let mut matches: Vec<Match> = digits.matches(line).collect();
+    matches.sort_by_key(|m| (m.start, m.end));
+    let mut runs: Vec<(usize, usize, usize)> = vec![];
+    for m in matches {
+        match runs.last_mut() {
+            Some((_, end, count)) if m.start < *end => {
+                *end = (*end).max(m.end);
+                *count += 1;
+            }
+            _ => runs.push((m.start, m.end, 1)),
+        }
+    }
+    runs.into_iter()
+        .filter(|&(_, _, count)| count > 1)
+        .map(|(start, end, _)| Flag::Overlap {
+            start,
+            end,
+            text: line[start..end].to_string(),
+        })
+        .collect()
+}
+
+pub fn report(input: &str, digits: &DigitScanner) -> Vec<Line> {
+    let input = normalize(input);
+    Scanner::new(&input)
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let text = line.rest();
+            let first = digits.first(text).map(|m| token(text, m));
+            let last = digits.last(text).map(|m| token(text, m));
+            let mut flags = vec![];
+            match (&first, &last) {
+                (Some(first), Some(last)) if first == last => flags.push(Flag::SingleDigit),
+                (None, _) | (_, None) => flags.push(Flag::NoDigits),
+                _ => {}
+            }
+            flags.extend(overlaps(text, digits));
+            Line {
+                number: i + 1,
+                text: text.to_string(),
+                value: digits.value(text),
+                first,
+                last,
+                flags,
+            }
+        })
+        .collect()
+}
+
+fn kind_name(kind: Kind) -> &'static str {
+    match kind {
+        Kind::Digit => "digit",
+        Kind::Word => "word",
+    }
+}
+
+/// One row per line, or per flagged line with `flagged`, then the totals
+/// over every line.
+pub fn text(lines: &[Line], flagged: bool) -> String {
+    let show = |token: &Option<Token>| match token {
+        Some(t) => format!("{} {}..{} {}", t.text, t.start, t.end, kind_name(t.kind)),
+        None => "-".to_string(),
+    };
+    let mut out = format!(
+        "{:>5} {:>5}  {:<20} {:<20} flags\n",
+        "line", "value", "first", "last"
+    );
+    for line in lines
+        .iter()
+        .filter(|line| !flagged || !line.flags.is_empty())
+    {
+        let flags: Vec<String> = line
+            .flags
+            .iter()
+            .map(|flag| match flag {
+                Flag::Overlap { start, end, text } => format!("overlap {text} {start}..{end}"),
+                flag => flag.name().to_string(),
+            })
+            .collect();
+        let row = format!(
+            "{:>5} {:>5}  {:<20} {:<20} {}",
+            line.number,
+            line.value,
+            show(&line.first),
+            show(&line.last),
+            flags.join(", ")
+        );
+        let _ = writeln!(out, "{}", row.trim_end());
+    }
+    let sum: u64 = lines.iter().map(|line| u64::from(line.value)).sum();
+    let count = |name| {
+        lines
+            .iter()
+            .filter(|line| line.flags.iter().any(|flag| flag.name() == name))
+            .count()
+    };
+    let _ = writeln!(
+        out,
+        "{} lines, sum {sum}: {} without digits, {} with a single digit, {} with overlapping matches",
+        lines.len(),
+        count("no-digits"),
+        count("single-digit"),
+        count("overlap")
+    );
+    out
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn json_token(token: &Option<Token>) -> String {
+    match token {
+        Some(t) => format!(
+            "{{\"text\":{},\"start\":{},\"end\":{},\"value\":{},\"kind\":\"{}\"}}",
+            json_string(&t.text),
+            t.start,
+            t.end,
+            t.value,
+            kind_name(t.kind)
+        ),
+        None => "null".to_string(),
+    }
+}
+
+/// The same as [`text`], as one JSON object with a `lines` array.
+pub fn json(lines: &[Line], flagged: bool) -> String {
+    let lines_json: Vec<String> = lines
+        .iter()
+        .filter(|line| !flagged || !line.flags.is_empty())
+        .map(|line| {
+            let flags: Vec<String> = line
+                .flags
+                .iter()
+                .map(|flag| match flag {
+                    Flag::Overlap { start, end, text } => format!(
+                        "{{\"flag\":\"overlap\",\"start\":{start},\"end\":{end},\"text\":{}}}",
+                        json_string(text)
+                    ),
+                    flag => format!("{{\"flag\":\"{}\"}}", flag.name()),
+                })
+                .collect();
+            format!(
+                "{{\"line\":{},\"text\":{},\"value\":{},\"first\":{},\"last\":{},\"flags\":[{}]}}",
+                line.number,
+                json_string(&line.text),
+                line.value,
+                json_token(&line.first),
+                json_token(&line.last),
+                flags.join(",")
+            )
+        })
+        .collect();
+    let sum: u64 = lines.iter().map(|line| u64::from(line.value)).sum();
+    format!(
+        "{{\"sum\":{sum},\"lines\":[\n{}\n]}}\n",
+        lines_json.join(",\n")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vocabulary::Vocabulary;
+    use aoc::input::example;
+
+    #[test]
+    fn flags_what_the_sum_hides() {
+        let input = example(
+            "xtwone3four
+            treb7uchet
+            nothing here",
+        );
+        let lines = report(&input, &Vocabulary::english().scanner());
+        assert_eq!(
+            lines[0].first,
+            Some(Token {
+                text: "two".to_string(),
+                start: 1,
+                end: 4,
+                value: 2,
+                kind: Kind::Word,
+            })
+        );
+        assert_eq!(lines[0].last.as_ref().map(|t| t.start..t.end), Some(7..11));
+        assert_eq!(
+            lines[0].flags,
+            [Flag::Overlap {
+                start: 1,
+                end: 6,
+                text: "twone".to_string(),
+            }]
+        );
+        assert_eq!(
+            (lines[1].value, &lines[1].flags[..]),
+            (77, &[Flag::SingleDigit][..])
+        );
+        assert_eq!(
+            (lines[2].value, &lines[2].flags[..]),
+            (0, &[Flag::NoDigits][..])
+        );
+
+        let listing = text(&lines, false);
+        assert!(
+            listing
+                .contains("    2    77  7 4..5 digit         7 4..5 digit         single-digit\n"),
+            "{listing}"
+        );
+        assert!(listing.ends_with(
+            "3 lines, sum 101: 1 without digits, 1 with a single digit, 1 with overlapping matches\n"
+        ));
+        let flagged = text(&lines, true);
+        assert!(!flagged.contains("treb7uchet") && flagged.ends_with("sum 101: 1 without digits, 1 with a single digit, 1 with overlapping matches\n"));
+
+        let object = json(&lines, false);
+        assert!(
+            object.starts_with(
+                "{\"sum\":101,\"lines\":[\n{\"line\":1,\"text\":\"xtwone3four\",\"value\":24,\
+                 \"first\":{\"text\":\"two\",\"start\":1,\"end\":4,\"value\":2,\"kind\":\"word\"},"
+            ),
+            "{object}"
+        );
+        assert!(object.contains(
+            "\"flags\":[{\"flag\":\"overlap\",\"start\":1,\"end\":6,\"text\":\"twone\"}]}"
+        ));
+        assert!(object
+            .ends_with("\"first\":null,\"last\":null,\"flags\":[{\"flag\":\"no-digits\"}]}\n]}\n"));
+
+        // Lines worth 109989 each add up past a u32.
+        let big = Vocabulary::parse("big 9999").unwrap();
+        let lines = report(&vec!["big"; 40_000].join("\n"), &big.scanner());
+        assert!(text(&lines, false).contains("40000 lines, sum 4399560000:"));
+        assert!(json(&lines, true).starts_with("{\"sum\":4399560000,"));
+    }
+}
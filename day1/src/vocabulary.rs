@@ -0,0 +1,254 @@
+//! The words part 2 reads as digits, and a scanner that finds them.
+//!
+//! The puzzle's words are English, but a vocabulary can come from a file with
+//! one `word value` pair per line, `#` starting a comment:
+//!
+//! ```text
+//! # Spanish
+//! digits 1-9
+//! uno 1
+//! dos 2
+//! ```
+//!
+//! `digits 1-9` stands for the numerals `1` to `9` with their own values;
+//! without it only the words count. A value above 9 is allowed, up to
+//! [`MAX_VALUE`]. As the first match it carries into the hundreds, so `doce`
+//! then `dos` makes 122, and as the last match it's added on whole, so `uno`
+//! then `doce` makes 22.
+
+use std::fs;
+use std::path::Path;
+
+use aoc::parse::{self, Scanner};
+
+use crate::automaton::{Automaton, Match};
+
+/// The biggest value a word can have, which keeps a line's value, ten times
+/// the first match plus the last, well inside a `u32`. A sum over many lines
+/// can still outgrow one, so sums are taken in a `u64`.
+pub const MAX_VALUE: u32 = 9999;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vocabulary {
+    entries: Vec<(String, u32)>,
+}
+
+impl Vocabulary {
+    /// The numerals 1 to 9 and their English names, as the puzzle reads them.
+    pub fn english() -> Vocabulary {
+        let words = [
+            "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        ];
+        let entries = (1..=9)
+            .map(|digit: u32| (digit.to_string(), digit))
+            .chain(words.into_iter().map(String::from).zip(1..=9))
+            .collect();
+        Vocabulary { entries }
+    }
+
+    /// The numerals 0 to 9 alone, as part 1 reads them.
+    pub fn numerals() -> Vocabulary {
+        let entries = (0..=9)
+            .map(|digit: u32| (digit.to_string(), digit))
+            .collect();
+        Vocabulary { entries }
+    }
+
+    pub fn parse(text: &str) -> parse::Result<Vocabulary> {
+        let mut entries: Vec<(String, u32)> = vec![];
+        // `at` is where the word came from, for errors.
+        let mut add = |line: &Scanner, at: &str, word: &str, value: u32| match entries
+            .iter()
+            .find(|(known, _)| known == word)
+        {
+            Some((_, known)) if *known != value => {
+                Err(line.error_at(at, format!("`{word}` is already {known}")))
+            }
+            Some(_) => Ok(()),
+            None => {
+                entries.push((word.to_string(), value));
+                Ok(())
+            }
+        };
+
+        let text = Scanner::new(text);
+        for mut line in text.lines() {
+            line.skip_spaces();
+            if line.is_empty() || line.rest().starts_with('#') {
+                continue;
+            }
+            let word = line.token()?;
+            if word == "digits" {
+                let range = line.token()?;
+                let digits = range
+                    .split_once('-')
+                    .and_then(|(low, high)| Some((low.parse().ok()?, high.parse().ok()?)))
+                    .filter(|&(low, high): &(u32, u32)| low <= high && high <= 9);
+                let Some((low, high)) = digits else {
+                    return Err(line.error_at(range, "expected a range of digits like `1-9`"));
+                };
+                for digit in low..=high {
+                    add(&line, range, &digit.to_string(), digit)?;
+                }
+            } else {
+                line.skip_spaces();
+                let at = line.rest();
+                let value = line.int()?;
+                if value > MAX_VALUE {
+                    return Err(line.error_at(at, format!("`{value}` is over {MAX_VALUE}")));
+                }
+                add(&line, word, word, value)?;
+            }
+            line.skip_spaces();
+            if !line.rest().starts_with('#') {
+                line.finish()?;
+            }
+        }
+        if entries.is_empty() {
+            return Err(text.error("no words in the vocabulary"));
+        }
+        Ok(Vocabulary { entries })
+    }
+
+    pub fn load(path: &Path) -> Result<Vocabulary, String> {
+        let text =
+            fs::read_to_string(path).map_err(|e| format!("can't read {}: {e}", path.display()))?;
+        Vocabulary::parse(&text).map_err(|e| format!("{}: {e}", path.display()))
+    }
+
+    pub fn scanner(&self) -> DigitScanner {
+        let words = || {
+            self.entries
+                .iter()
+                .map(|(word, value)| (word.as_bytes(), *value))
+        };
+        let reversed =
+            words().map(|(word, value)| (word.iter().rev().copied().collect::<Vec<_>>(), value));
+        DigitScanner {
+            forward: Automaton::new(words()),
+            backward: Automaton::new(reversed),
+        }
+    }
+}
+
+/// Finds the first and last words of a vocabulary in a line, each by reading
+/// from its own end of the line: the last match is the first one in the line
+/// read backwards, against the words spelled backwards. Neither end has to
+/// read the whole line, and a word that is a prefix or suffix of another
+/// loses to the longer one at either end.
+pub struct DigitScanner {
+    forward: Automaton,
+    backward: Automaton,
+}
+
+impl DigitScanner {
+    /// The match starting first, the longest one if several do.
+    pub fn first(&self, line: &str) -> Option<Match> {
+        self.forward.leftmost(line.bytes())
+    }
+
+    /// The match ending last, the longest one if several do.
+    pub fn last(&self, line: &str) -> Option<Match> {
+        let m = self.backward.leftmost(line.bytes().rev())?;
+        Some(Match {
+            start: line.len() - m.end,
+            end: line.len() - m.start,
+            value: m.value,
+        })
+    }
+
+    /// Every match in `line`, overlapping ones included, in order of where
+    /// they end.
+    pub fn matches<'a>(&'a self, line: &'a str) -> impl Iterator<Item = Match> + 'a {
+        self.forward.matches(line.bytes())
+    }
+
+    /// The line's first and last digit as a two-digit number, or 0 when it
+    /// has none. Values above 9 don't fit two digits and are added as the
+    /// module docs say.
+    pub fn value(&self, line: &str) -> u32 {
+        match (self.first(line), self.last(line)) {
+            (Some(first), Some(last)) => first.value * 10 + last.value,
+            _ => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::prop;
+
+    #[test]
+    fn either_end_prefers_the_longer_word() {
+        let english = Vocabulary::english().scanner();
+        let found = |text| english.value(text);
+        assert_eq!(found("eightwo"), 82);
+        assert_eq!(found("xtwone3four"), 24);
+        assert_eq!(found("7pqrstsixteen"), 76);
+        assert_eq!(found("oneight"), 18);
+        assert_eq!(found("zero"), 0);
+
+        // `dieciseis` starts with `die` and ends in `seis`, which ends in `is`.
+        let spanish = Vocabulary::parse(
+            "# from a file\n\
+             digits 1-9\n\
+             \n\
+             seis 6\n\
+             is 2   # not really\n\
+             die 3\n\
+             dieciseis 16\n",
+        )
+        .unwrap();
+        let scanner = spanish.scanner();
+        let m = scanner.first("xdieciseis").unwrap();
+        assert_eq!((m.start, m.end, m.value), (1, 10, 16));
+        let m = scanner.last("dieciseisx").unwrap();
+        assert_eq!((m.start, m.end, m.value), (0, 9, 16));
+        assert_eq!(scanner.value("dies7seis"), 36);
+        assert_eq!(scanner.value("dieciseis2"), 162);
+        assert_eq!(scanner.value("1dieciseis"), 26);
+
+        let error = |text| Vocabulary::parse(text).unwrap_err().to_string();
+        assert_eq!(error("uno\n"), "line 1, column 4: expected an integer");
+        assert_eq!(
+            error("uno 1\nuno 2\n7 3\n"),
+            "line 2, column 1: `uno` is already 1"
+        );
+        assert_eq!(
+            error("7 3\ndigits 5-8"),
+            "line 2, column 8: `7` is already 3"
+        );
+        assert_eq!(
+            error("digits 1-10"),
+            "line 1, column 8: expected a range of digits like `1-9`"
+        );
+        assert_eq!(
+            error("mil 1000\nmillon 4294967295\n"),
+            "line 2, column 8: `4294967295` is over 9999"
+        );
+        assert_eq!(
+            error("# nothing\n"),
+            "line 1, column 1: no words in the vocabulary"
+        );
+
+        // Both ends agree with picking from every match.
+        let words = [("ab", 1), ("b", 2), ("abc", 3), ("ca", 4), ("bcab", 5)];
+        let vocabulary = Vocabulary {
+            entries: words
+                .map(|(word, value)| (word.to_string(), value))
+                .to_vec(),
+        };
+        let scanner = vocabulary.scanner();
+        let forward = Automaton::new(words);
+        prop::check(
+            |source| source.string("abcx", 0..=16),
+            |text| {
+                let all: Vec<Match> = forward.matches(text.bytes()).collect();
+                let first = all.iter().copied().min_by_key(|m| (m.start, !m.end));
+                let last = all.iter().copied().max_by_key(|m| (m.end, !m.start));
+                scanner.first(text) == first && scanner.last(text) == last
+            },
+        );
+    }
+}
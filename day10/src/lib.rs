@@ -0,0 +1,4 @@
+//! Day 10: Pipe Maze
+
+pub mod part1;
+pub mod part2;
@@ -0,0 +1,4 @@
+//! Day 11: Cosmic Expansion
+
+pub mod part1;
+pub mod part2;
@@ -0,0 +1,4 @@
+//! Day 12: Hot Springs
+
+pub mod part1;
+pub mod part2;
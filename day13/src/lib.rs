@@ -0,0 +1,4 @@
+//! Day 13: Point of Incidence
+
+pub mod part1;
+pub mod part2;
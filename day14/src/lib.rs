@@ -0,0 +1,4 @@
+//! Day 14: Parabolic Reflector Dish
+
+pub mod part1;
+pub mod part2;
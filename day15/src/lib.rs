@@ -0,0 +1,4 @@
+//! Day 15: Lens Library
+
+pub mod part1;
+pub mod part2;
@@ -0,0 +1,4 @@
+//! Day 16: The Floor Will Be Lava
+
+pub mod part1;
+pub mod part2;
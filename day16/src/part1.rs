@@ -0,0 +1,314 @@
+use core::fmt;
+
+use aoc::bits::BitGrid;
+use aoc::input::normalize;
+use aoc::parse::Scanner;
+use aoc::render::Palette;
+use aoc::trace::Tracer;
+
+/**
+--- Day 16: The Floor Will Be Lava ---
+
+With the beam of light completely focused somewhere, the
+reindeer leads you deeper still into the Lava Production
+Facility. At some point, you realize that the steel facility
+walls have been replaced with cave, and the doorways are
+just cave, and the floor is cave, and you're pretty sure this
+is actually just a giant cave.
+
+Finally, as you approach what must be the heart of the mountain,
+you see a bright light in a cavern up ahead. There, you discover
+that the beam of light you so carefully focused is emerging from
+the cavern wall closest to the facility and pouring all of its
+energy into a contraption on the opposite side.
+
+Upon closer inspection, the contraption appears to be a flat,
+two-dimensional square grid containing empty space (.),
+mirrors (/ and \), and splitters (| and -).
+
+The contraption is aligned so that most of the beam bounces
+around the grid, but each tile on the grid converts some of
+the beam's light into heat to melt the rock in the cavern.
+
+You note the layout of the contraption (your puzzle input).
+For example:
+
+.|...\....
+|.-.\.....
+.....|-...
+........|.
+..........
+.........\
+..../.\\..
+.-.-/..|..
+.|....-|.\
+..//.|....
+
+The beam enters in the top-left corner from the left and
+heading to the right. Then, its behavior depends on what
+it encounters as it moves:
+
+    If the beam encounters empty space (.), it continues in the same direction.
+    If the beam encounters a mirror (/ or \), the beam is reflected 90 degrees
+        depending on the angle of the mirror. For instance, a rightward-moving
+        beam that encounters a / mirror would continue upward in the mirror's
+        column, while a rightward-moving beam that encounters a \ mirror would
+        continue downward from the mirror's column.
+    If the beam encounters the pointy end of a splitter (| or -), the beam passes
+        through the splitter as if the splitter were empty space. For instance, a
+        rightward-moving beam that encounters a - splitter would continue in the
+        same direction.
+    If the beam encounters the flat side of a splitter (| or -), the beam is split
+        into two beams going in each of the two directions the splitter's pointy
+        ends are pointing. For instance, a rightward-moving beam that encounters
+        a | splitter would split into two beams: one that continues upward from
+        the splitter's column and one that continues downward from the splitter's
+        column.
+
+Beams do not interact with other beams; a tile can have many beams passing
+through it at the same time. A tile is energized if that tile has at least
+one beam pass through it, reflect in it, or split in it.
+
+In the above example, here is how the beam of light bounces around the contraption:
+
+>|<<<\....
+|v-.\^....
+.v...|->>>
+.v...v^.|.
+.v...v^...
+.v...v^..\
+.v../2\\..
+<->-/vv|..
+.|<<<2-|.\
+.v//.|.v..
+
+Beams are only shown on empty tiles; arrows indicate the direction of the beams.
+If a tile contains beams moving in multiple directions, the number of distinct
+directions is shown instead. Here is the same diagram but instead only showing
+whether a tile is energized (#) or not (.):
+
+######....
+.#...#....
+.#...#####
+.#...##...
+.#...##...
+.#...##...
+.#..####..
+########..
+.#######..
+.#...#.#..
+
+Ultimately, in this example, 46 tiles become energized.
+
+The light isn't energizing enough tiles to produce lava; to debug the contraption,
+you need to start by analyzing the current situation. With the beam starting in
+the top-left heading right, how many tiles end up being energized?
+
+
+*/
+#[derive(Debug, Clone)]
+enum SplitType {
+    Horizontal,
+    Vertical,
+}
+
+#[derive(Debug, Clone)]
+enum MirrorType {
+    Forward,
+    Back,
+}
+
+#[derive(Debug, Clone)]
+enum SpaceType {
+    Split(SplitType),
+    Mirror(MirrorType),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    /// This direction's bit in the flags of a tile in `Grid::beams`.
+    fn flag(self) -> u8 {
+        1 << self as u8
+    }
+
+    /// Where a beam leaving `(x, y)` this way goes next, unless that's off the
+    /// top or the left of the grid.
+    fn step(self, x: usize, y: usize) -> Option<(usize, usize, Direction)> {
+        use Direction::*;
+        match self {
+            Up => Some((x, y.checked_sub(1)?, Up)),
+            Down => Some((x, y + 1, Down)),
+            Left => Some((x.checked_sub(1)?, y, Left)),
+            Right => Some((x + 1, y, Right)),
+        }
+    }
+}
+
+/// The ways a beam going `direction` leaves a tile.
+fn outgoing(space_type: &Option<SpaceType>, direction: Direction) -> &'static [Direction] {
+    use Direction::*;
+    use MirrorType::*;
+    use SpaceType::*;
+    use SplitType::*;
+    match (space_type, direction) {
+        (Some(Split(Horizontal)), Up | Down) => &[Left, Right],
+        (Some(Split(Vertical)), Left | Right) => &[Up, Down],
+        (Some(Mirror(Forward)), Right) | (Some(Mirror(Back)), Left) => &[Up],
+        (Some(Mirror(Forward)), Left) | (Some(Mirror(Back)), Right) => &[Down],
+        (Some(Mirror(Forward)), Down) | (Some(Mirror(Back)), Up) => &[Left],
+        (Some(Mirror(Forward)), Up) | (Some(Mirror(Back)), Down) => &[Right],
+        (_, Up) => &[Up],
+        (_, Down) => &[Down],
+        (_, Left) => &[Left],
+        (_, Right) => &[Right],
+    }
+}
+
+#[derive(Debug)]
+struct Grid {
+    tiles: Vec<Vec<Option<SpaceType>>>,
+    /// The directions beams have entered each tile in.
+    beams: BitGrid,
+    energy: Vec<(usize, usize, Direction)>,
+    size_x: usize,
+    size_y: usize,
+}
+
+impl Grid {
+    fn new(input: &str) -> Grid {
+        let mut tiles = Vec::new();
+        let mut size_y = 0;
+        let mut size_x = 0;
+        let rows = Scanner::new(input)
+            .grid()
+            .expect("contraption should be rectangular");
+        for (y, line) in rows.rows.iter().enumerate() {
+            size_y = size_y.max(y);
+            let mut row = Vec::new();
+            for (x, c) in line.chars().enumerate() {
+                size_x = size_x.max(x);
+                row.push(match c {
+                    '.' => None,
+                    '-' => Some(SpaceType::Split(SplitType::Horizontal)),
+                    '|' => Some(SpaceType::Split(SplitType::Vertical)),
+                    '/' => Some(SpaceType::Mirror(MirrorType::Forward)),
+                    '\\' => Some(SpaceType::Mirror(MirrorType::Back)),
+                    _ => unreachable!("Bad input {c}"),
+                });
+            }
+            tiles.push(row);
+        }
+        Grid {
+            tiles,
+            beams: BitGrid::with_flags(size_x + 1, size_y + 1, 4),
+            energy: vec![],
+            size_x,
+            size_y,
+        }
+    }
+
+    fn init(&mut self, tracer: &mut Tracer) {
+        self.energy.push((0, 0, Direction::Right));
+        tracer.push((0, 0, Direction::Right));
+
+        // dbg!(self);
+    }
+
+    fn process(&mut self, tracer: &mut Tracer) {
+        while let Some((x, y, direction)) = self.energy.pop() {
+            tracer.pop((x, y, direction));
+            let queued = self.energy.len();
+            let on_grid = x <= self.size_x && y <= self.size_y;
+            // A beam that already went this way through the tile would only
+            // retrace it.
+            if on_grid && self.beams.insert_flags((x, y), direction.flag()) {
+                for &out in outgoing(&self.tiles[y][x], direction) {
+                    if let Some(beam) = out.step(x, y) {
+                        self.energy.push(beam);
+                    }
+                }
+            }
+            for beam in &self.energy[queued..] {
+                tracer.push(beam);
+            }
+            tracer.state(&*self);
+        }
+    }
+
+    fn get_energized(&self) -> usize {
+        self.beams.count()
+    }
+}
+
+impl fmt::Display for Grid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut output = String::new();
+
+        for row in 0..=self.size_y {
+            for col in 0..=self.size_x {
+                if self.beams.contains((col, row)) {
+                    output.push('#');
+                } else {
+                    match self.tiles[row][col] {
+                        Some(SpaceType::Split(SplitType::Horizontal)) => output.push('-'),
+                        Some(SpaceType::Split(SplitType::Vertical)) => output.push('|'),
+                        Some(SpaceType::Mirror(MirrorType::Forward)) => output.push('/'),
+                        Some(SpaceType::Mirror(MirrorType::Back)) => output.push('\\'),
+                        None => output.push('.'),
+                    }
+                }
+                // output.push(' ');
+            }
+            output.push('\n');
+        }
+
+        write!(f, "{}", output)
+    }
+}
+
+/// Colours for `--render`: energized tiles lit up, mirrors and splitters in blue.
+pub fn palette() -> Palette {
+    Palette::new([20, 20, 30])
+        .tiles("#", [255, 200, 60])
+        .tiles("/\\|-", [110, 160, 255])
+}
+
+pub fn process(input: &str) -> String {
+    let input = normalize(input);
+    let mut grid = Grid::new(&input);
+
+    let mut tracer = Tracer::from_env();
+    grid.init(&mut tracer);
+    grid.process(&mut tracer);
+
+    return grid.get_energized().to_string();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::process;
+
+    #[test]
+    fn it_works() {
+        let result = process(
+            ".|...\\....
+|.-.\\.....
+.....|-...
+........|.
+..........
+.........\\
+..../.\\\\..
+.-.-/..|..
+.|....-|.\\
+..//.|....",
+        );
+        assert_eq!(result, "46".to_string());
+    }
+}
@@ -0,0 +1,268 @@
+use core::fmt;
+
+use aoc::bits::BitGrid;
+use aoc::input::normalize;
+use aoc::parse::Scanner;
+use aoc::render::Palette;
+use aoc::trace::Tracer;
+
+/**--- Part Two ---
+
+As you try to work out what might be wrong, the reindeer tugs on your
+shirt and leads you to a nearby control panel. There, a collection of
+buttons lets you align the contraption so that the beam enters from any
+edge tile and heading away from that edge. (You can choose either of two
+    directions for the beam if it starts on a corner; for instance, if
+    the beam starts in the bottom-right corner, it can start heading either
+    left or upward.)
+
+So, the beam could start on any tile in the top row (heading downward),
+any tile in the bottom row (heading upward), any tile in the leftmost
+column (heading right), or any tile in the rightmost column (heading left).
+To produce lava, you need to find the configuration that energizes as many
+tiles as possible.
+
+In the above example, this can be achieved by starting the beam in the
+fourth tile from the left in the top row:
+
+.|<2<\....
+|v-v\^....
+.v.v.|->>>
+.v.v.v^.|.
+.v.v.v^...
+.v.v.v^..\
+.v.v/2\\..
+<-2-/vv|..
+.|<<<2-|.\
+.v//.|.v..
+
+Using this configuration, 51 tiles are energized:
+
+.#####....
+.#.#.#....
+.#.#.#####
+.#.#.##...
+.#.#.##...
+.#.#.##...
+.#.#####..
+########..
+.#######..
+.#...#.#..
+
+Find the initial beam configuration that energizes the largest number
+of tiles; how many tiles are energized in that configuration?
+*/
+#[derive(Debug, Clone)]
+enum SplitType {
+    Horizontal,
+    Vertical,
+}
+
+#[derive(Debug, Clone)]
+enum MirrorType {
+    Forward,
+    Back,
+}
+
+#[derive(Debug, Clone)]
+enum SpaceType {
+    Split(SplitType),
+    Mirror(MirrorType),
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    /// This direction's bit in the flags of a tile in `Grid::beams`.
+    fn flag(self) -> u8 {
+        1 << self as u8
+    }
+
+    /// Where a beam leaving `(x, y)` this way goes next, unless that's off the
+    /// top or the left of the grid.
+    fn step(self, x: usize, y: usize) -> Option<(usize, usize, Direction)> {
+        use Direction::*;
+        match self {
+            Up => Some((x, y.checked_sub(1)?, Up)),
+            Down => Some((x, y + 1, Down)),
+            Left => Some((x.checked_sub(1)?, y, Left)),
+            Right => Some((x + 1, y, Right)),
+        }
+    }
+}
+
+/// The ways a beam going `direction` leaves a tile.
+fn outgoing(space_type: &Option<SpaceType>, direction: Direction) -> &'static [Direction] {
+    use Direction::*;
+    use MirrorType::*;
+    use SpaceType::*;
+    use SplitType::*;
+    match (space_type, direction) {
+        (Some(Split(Horizontal)), Up | Down) => &[Left, Right],
+        (Some(Split(Vertical)), Left | Right) => &[Up, Down],
+        (Some(Mirror(Forward)), Right) | (Some(Mirror(Back)), Left) => &[Up],
+        (Some(Mirror(Forward)), Left) | (Some(Mirror(Back)), Right) => &[Down],
+        (Some(Mirror(Forward)), Down) | (Some(Mirror(Back)), Up) => &[Left],
+        (Some(Mirror(Forward)), Up) | (Some(Mirror(Back)), Down) => &[Right],
+        (_, Up) => &[Up],
+        (_, Down) => &[Down],
+        (_, Left) => &[Left],
+        (_, Right) => &[Right],
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Grid {
+    tiles: Vec<Vec<Option<SpaceType>>>,
+    /// The directions beams have entered each tile in.
+    beams: BitGrid,
+    energy: Vec<(usize, usize, Direction)>,
+    size_x: usize,
+    size_y: usize,
+}
+
+impl Grid {
+    fn new(input: &str) -> Grid {
+        let mut tiles = Vec::new();
+        let mut size_y = 0;
+        let mut size_x = 0;
+        let rows = Scanner::new(input)
+            .grid()
+            .expect("contraption should be rectangular");
+        for (y, line) in rows.rows.iter().enumerate() {
+            size_y = size_y.max(y);
+            let mut row = Vec::new();
+            for (x, c) in line.chars().enumerate() {
+                size_x = size_x.max(x);
+                row.push(match c {
+                    '.' => None,
+                    '-' => Some(SpaceType::Split(SplitType::Horizontal)),
+                    '|' => Some(SpaceType::Split(SplitType::Vertical)),
+                    '/' => Some(SpaceType::Mirror(MirrorType::Forward)),
+                    '\\' => Some(SpaceType::Mirror(MirrorType::Back)),
+                    _ => unreachable!("Bad input {c}"),
+                });
+            }
+            tiles.push(row);
+        }
+        Grid {
+            tiles,
+            beams: BitGrid::with_flags(size_x + 1, size_y + 1, 4),
+            energy: vec![],
+            size_x,
+            size_y,
+        }
+    }
+
+    fn process(&mut self, start: (usize, usize, Direction), tracer: &mut Tracer) -> usize {
+        self.energy.push(start);
+        self.beams.clear();
+        tracer.note(format!("start at {start:?}"));
+        tracer.push(start);
+
+        while let Some((x, y, direction)) = self.energy.pop() {
+            tracer.pop((x, y, direction));
+            let queued = self.energy.len();
+            let on_grid = x <= self.size_x && y <= self.size_y;
+            // A beam that already went this way through the tile would only
+            // retrace it.
+            if on_grid && self.beams.insert_flags((x, y), direction.flag()) {
+                for &out in outgoing(&self.tiles[y][x], direction) {
+                    if let Some(beam) = out.step(x, y) {
+                        self.energy.push(beam);
+                    }
+                }
+            }
+            for beam in &self.energy[queued..] {
+                tracer.push(beam);
+            }
+            tracer.state(&*self);
+        }
+
+        self.beams.count()
+    }
+}
+
+impl fmt::Display for Grid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut output = String::new();
+
+        for row in 0..=self.size_y {
+            for col in 0..=self.size_x {
+                if self.beams.contains((col, row)) {
+                    output.push('#');
+                } else {
+                    match self.tiles[row][col] {
+                        Some(SpaceType::Split(SplitType::Horizontal)) => output.push('-'),
+                        Some(SpaceType::Split(SplitType::Vertical)) => output.push('|'),
+                        Some(SpaceType::Mirror(MirrorType::Forward)) => output.push('/'),
+                        Some(SpaceType::Mirror(MirrorType::Back)) => output.push('\\'),
+                        None => output.push('.'),
+                    }
+                }
+                // output.push(' ');
+            }
+            output.push('\n');
+        }
+
+        write!(f, "{}", output)
+    }
+}
+
+/// Colours for `--render`: energized tiles lit up, mirrors and splitters in blue.
+pub fn palette() -> Palette {
+    Palette::new([20, 20, 30])
+        .tiles("#", [255, 200, 60])
+        .tiles("/\\|-", [110, 160, 255])
+}
+
+pub fn process(input: &str) -> String {
+    let input = normalize(input);
+    let mut grid = Grid::new(&input);
+
+    use Direction::*;
+    let mut to_process = vec![];
+    for x in 0..=grid.size_x {
+        to_process.push((x, 0, Down));
+        to_process.push((x, grid.size_y, Up));
+    }
+    for y in 0..=grid.size_y {
+        to_process.push((0, y, Right));
+        to_process.push((grid.size_x, y, Left));
+    }
+    let mut tracer = Tracer::from_env();
+    let score = to_process
+        .iter()
+        .map(|&start| grid.process(start, &mut tracer))
+        .max();
+
+    return score.unwrap().to_string();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::process;
+
+    #[test]
+    fn it_works() {
+        let result = process(
+            ".|...\\....
+|.-.\\.....
+.....|-...
+........|.
+..........
+.........\\
+..../.\\\\..
+.-.-/..|..
+.|....-|.\\
+..//.|....",
+        );
+        assert_eq!(result, "51".to_string());
+    }
+}
@@ -0,0 +1,4 @@
+//! Day 17: Clumsy Crucible
+
+pub mod part1;
+pub mod part2;
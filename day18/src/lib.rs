@@ -0,0 +1,4 @@
+//! Day 18: Lavaduct Lagoon
+
+pub mod part1;
+pub mod part2;
@@ -0,0 +1,4 @@
+//! Day 19: Aplenty
+
+pub mod part1;
+pub mod part2;
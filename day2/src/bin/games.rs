@@ -0,0 +1,41 @@
+//! Checks every game against a bag, with whatever colours either has.
+//!
+//! cargo run --bin games -- [--bag "12 red, 13 green, 14 blue" | --bag-file <file>] [<input>]
+//!
+//! The puzzle's bag and `src/bin/input1.txt` unless told otherwise. A bag
+//! file lists the same counts and colours, by comma or by line.
+
+use aoc::args::{fail, read, Args};
+use day2::cubes::{self, Cubes};
+
+const USAGE: &str = "usage: [--bag <cubes> | --bag-file <file>] [<input>]";
+
+fn main() {
+    let mut bag = None;
+    let mut input = None;
+    let mut args = Args::new(USAGE);
+    while let Some(arg) = args.next() {
+        let (text, source) = match arg.as_str() {
+            "--bag" => {
+                let text = args.value();
+                (text, "--bag".to_string())
+            }
+            "--bag-file" => {
+                let file = args.value();
+                (read(&file), file)
+            }
+            file if !file.starts_with("--") && input.is_none() => {
+                input = Some(read(file));
+                continue;
+            }
+            _ => args.usage(),
+        };
+        let parsed = Cubes::parse_bag(&text).unwrap_or_else(|e| fail(&format!("{source}: {e}")));
+        bag = Some(parsed);
+    }
+
+    let bag = bag.unwrap_or_else(Cubes::puzzle_bag);
+    let input = input.unwrap_or_else(|| include_str!("./input1.txt").to_string());
+    let games = cubes::parse(&input).unwrap_or_else(|e| fail(&e.to_string()));
+    print!("{}", cubes::report(&games, &bag));
+}
@@ -0,0 +1,69 @@
+//! Ranks the bags each game most likely came from.
+//!
+//! cargo run --bin infer -- [--total 39] [--colours "red, green, blue"] [--top 5] [--game <id>] [<input>]
+//!
+//! The total and colours default to the puzzle's bag; colours a game shows
+//! are always in its bags. `src/bin/input1.txt` unless given a file.
+
+use aoc::args::{fail, read, Args};
+use day2::cubes::{self, Cubes};
+use day2::infer;
+
+const USAGE: &str =
+    "usage: [--total <cubes>] [--colours <list>] [--top <count>] [--game <id>] [<input>]";
+
+fn main() {
+    let puzzle = Cubes::puzzle_bag();
+    let mut total = puzzle.colours().map(|(_, count)| count).sum();
+    let mut colours: Vec<String> = puzzle.colours().map(|(c, _)| c.to_string()).collect();
+    let mut top = 5;
+    let mut only = None;
+    let mut input = None;
+    let mut args = Args::new(USAGE);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--total" => total = args.number(),
+            "--top" => top = args.number(),
+            "--game" => only = Some(args.number::<u32>()),
+            "--colours" => {
+                let list = args.value();
+                colours = list
+                    .split(',')
+                    .map(|colour| colour.trim().to_string())
+                    .filter(|colour| !colour.is_empty())
+                    .collect();
+            }
+            file if !file.starts_with("--") && input.is_none() => input = Some(read(file)),
+            _ => args.usage(),
+        }
+    }
+
+    let input = input.unwrap_or_else(|| include_str!("./input1.txt").to_string());
+    let games = cubes::parse(&input).unwrap_or_else(|e| fail(&e.to_string()));
+    let colours: Vec<&str> = colours.iter().map(String::as_str).collect();
+    for game in games
+        .iter()
+        .filter(|game| only.is_none_or(|id| id == game.id))
+    {
+        match infer::infer(game, total, &colours, top) {
+            Ok(ranking) => {
+                println!(
+                    "Game {}: {} draws, {} bags of {total}",
+                    game.id,
+                    game.draws.len(),
+                    ranking.considered
+                );
+                for (rank, candidate) in ranking.candidates.iter().enumerate() {
+                    println!(
+                        "{:>4}  {:<40} likelihood {:.3e}  share {:5.1}%",
+                        rank + 1,
+                        candidate.bag.to_string(),
+                        candidate.likelihood(),
+                        candidate.share * 100.0
+                    );
+                }
+            }
+            Err(e) => println!("Game {}: {e}", game.id),
+        }
+    }
+}
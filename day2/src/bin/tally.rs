@@ -0,0 +1,53 @@
+//! Both answers for a game log read a line at a time, with what was wrong
+//! with any line it had to skip.
+//!
+//! cargo run --bin tally -- [--bag "12 red, 13 green, 14 blue"] [<input> | -]
+//!
+//! `-` reads standard input; `src/bin/input1.txt` with no input given. Each
+//! problem goes to standard error as `line:column: kind: message` as soon as
+//! its line is read.
+
+use std::fs::File;
+use std::io::{self, BufReader};
+
+use aoc::args::{fail, Args};
+use day2::cubes::Cubes;
+use day2::stream;
+
+const USAGE: &str = "usage: [--bag <cubes>] [<input> | -]";
+
+fn main() {
+    let mut bag = Cubes::puzzle_bag();
+    let mut input = None;
+    let mut args = Args::new(USAGE);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--bag" => {
+                let text = args.value();
+                bag = Cubes::parse_bag(&text).unwrap_or_else(|e| fail(&format!("--bag: {e}")));
+            }
+            file if (file == "-" || !file.starts_with("--")) && input.is_none() => {
+                input = Some(file.to_string());
+            }
+            _ => args.usage(),
+        }
+    }
+
+    let report = |d: stream::Diagnostic| {
+        eprintln!("{}:{}: {}: {}", d.line, d.column, d.kind.name(), d.message)
+    };
+    let totals = match input.as_deref() {
+        None => stream::tally(&include_bytes!("./input1.txt")[..], bag, report),
+        Some("-") => stream::tally(io::stdin().lock(), bag, report),
+        Some(file) => {
+            let file =
+                File::open(file).unwrap_or_else(|e| fail(&format!("can't read {file}: {e}")));
+            stream::tally(BufReader::new(file), bag, report)
+        }
+    }
+    .unwrap_or_else(|e| fail(&format!("can't read the log: {e}")));
+    println!(
+        "{} games, {} lines skipped\npart 1: {}\npart 2: {}",
+        totals.games, totals.skipped, totals.possible, totals.power
+    );
+}
@@ -0,0 +1,304 @@
+//! The cube game with cubes of any colour.
+//!
+//! A handful shown from the bag, the bag itself and the smallest bag a game
+//! could have been played with are all [`Cubes`]: a count for each colour
+//! named, and none of any colour that isn't. A game is possible with a bag
+//! when its minimal bag fits inside it.
+
+use std::collections::BTreeMap;
+use std::fmt::{self, Write};
+
+use aoc::input::normalize;
+use aoc::parse::{ParseError, Scanner};
+
+/// Why some cubes couldn't be read, with where.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    Malformed(ParseError),
+    /// A colour named twice in one list.
+    RepeatedColour {
+        colour: String,
+        at: ParseError,
+    },
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    pub fn location(&self) -> &ParseError {
+        match self {
+            Error::Malformed(at) | Error::RepeatedColour { at, .. } => at,
+        }
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(error: ParseError) -> Error {
+        Error::Malformed(error)
+    }
+}
+
+impl From<Error> for ParseError {
+    fn from(error: Error) -> ParseError {
+        match error {
+            Error::Malformed(at) | Error::RepeatedColour { at, .. } => at,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.location().fmt(f)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Cubes {
+    counts: BTreeMap<String, u32>,
+}
+
+impl Cubes {
+    /// The bag part 1 asks about.
+    pub fn puzzle_bag() -> Cubes {
+        let mut bag = Cubes::default();
+        bag.insert("red", 12);
+        bag.insert("green", 13);
+        bag.insert("blue", 14);
+        bag
+    }
+
+    pub fn insert(&mut self, colour: &str, count: u32) {
+        self.counts.insert(colour.to_string(), count);
+    }
+
+    pub fn get(&self, colour: &str) -> u32 {
+        self.counts.get(colour).copied().unwrap_or(0)
+    }
+
+    /// Each colour and its count, by colour name.
+    pub fn colours(&self) -> impl Iterator<Item = (&str, u32)> + '_ {
+        self.counts
+            .iter()
+            .map(|(colour, &count)| (colour.as_str(), count))
+    }
+
+    /// The counts of `bag`'s colours multiplied together, so a colour that
+    /// isn't here makes the power 0 and one that isn't in `bag` doesn't count.
+    pub fn power(&self, bag: &Cubes) -> u64 {
+        bag.colours()
+            .map(|(colour, _)| u64::from(self.get(colour)))
+            .product()
+    }
+
+    /// Raises each count to at least `other`'s.
+    pub fn max_with(&mut self, other: &Cubes) {
+        for (colour, count) in other.colours() {
+            if count > self.get(colour) {
+                self.insert(colour, count);
+            }
+        }
+    }
+
+    /// The colours there are more of here than in `bag`, with both counts.
+    pub fn excess<'a>(&'a self, bag: &Cubes) -> Vec<(&'a str, u32, u32)> {
+        self.colours()
+            .filter(|&(colour, count)| count > bag.get(colour))
+            .map(|(colour, count)| (colour, count, bag.get(colour)))
+            .collect()
+    }
+
+    fn add(&mut self, mut cubes: Scanner) -> Result<()> {
+        let count = cubes.int::<u32>()?;
+        cubes.skip_spaces();
+        let colour = cubes.rest().trim_end();
+        if colour.is_empty() {
+            return Err(cubes.error("expected a colour").into());
+        }
+        if self.counts.contains_key(colour) {
+            return Err(Error::RepeatedColour {
+                colour: colour.to_string(),
+                at: cubes.error_at(colour, format!("`{colour}` more than once")),
+            });
+        }
+        self.insert(colour, count);
+        Ok(())
+    }
+
+    /// `3 blue, 4 red`: a count and a colour, which may be more than one
+    /// word, between each comma.
+    pub fn parse(list: Scanner) -> Result<Cubes> {
+        let mut cubes = Cubes::default();
+        for piece in list.split(',') {
+            cubes.add(piece)?;
+        }
+        Ok(cubes)
+    }
+
+    /// A bag as given on the command line or in a file: the same list as
+    /// [`Cubes::parse`] reads, but split over lines too, and with empty
+    /// entries skipped.
+    pub fn parse_bag(text: &str) -> Result<Cubes> {
+        let mut bag = Cubes::default();
+        for line in Scanner::new(text).lines() {
+            for piece in line.split(',') {
+                if !piece.rest().trim().is_empty() {
+                    bag.add(piece)?;
+                }
+            }
+        }
+        Ok(bag)
+    }
+}
+
+impl fmt::Display for Cubes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.counts.is_empty() {
+            return write!(f, "no cubes");
+        }
+        for (i, (colour, count)) in self.colours().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{count} {colour}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    pub id: u32,
+    /// What each handful showed.
+    pub draws: Vec<Cubes>,
+}
+
+impl Game {
+    /// `Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green`
+    pub fn parse(line: Scanner) -> Result<Game> {
+        let mut key = line;
+        key.tag("Game")?;
+        let id = key.int()?;
+        // A missing `:` is reported just after the ID, not at the line's start.
+        let (mut gap, draws) = key.header().map_err(|_| key.error("expected `:`"))?;
+        gap.finish()?;
+        let draws = draws.split(';').map(Cubes::parse).collect::<Result<_>>()?;
+        Ok(Game { id, draws })
+    }
+
+    /// The fewest cubes of each colour that could have shown every draw.
+    pub fn minimal_bag(&self) -> Cubes {
+        let mut bag = Cubes::default();
+        for draw in &self.draws {
+            bag.max_with(draw);
+        }
+        bag
+    }
+
+    pub fn possible_with(&self, bag: &Cubes) -> bool {
+        self.minimal_bag().excess(bag).is_empty()
+    }
+}
+
+pub fn parse(input: &str) -> Result<Vec<Game>> {
+    let input = normalize(input);
+    Scanner::new(&input).lines().map(Game::parse).collect()
+}
+
+/// A line per game saying whether it was possible with `bag`, and if not
+/// which colours ran short, with its minimal bag and that bag's power over
+/// `bag`'s colours.
+pub fn report(games: &[Game], bag: &Cubes) -> String {
+    let mut out = format!("bag: {bag}\n");
+    let (mut ids, mut possible, mut powers) = (0, 0, 0);
+    for game in games {
+        let minimal = game.minimal_bag();
+        let short: Vec<String> = minimal
+            .excess(bag)
+            .iter()
+            .map(|(colour, shown, had)| format!("{shown} {colour} > {had}"))
+            .collect();
+        let verdict = if short.is_empty() {
+            ids += game.id;
+            possible += 1;
+            "possible".to_string()
+        } else {
+            format!("impossible, {}", short.join(", "))
+        };
+        powers += minimal.power(bag);
+        let _ = writeln!(
+            out,
+            "Game {}: {verdict}; minimal bag {minimal}, power {}",
+            game.id,
+            minimal.power(bag)
+        );
+    }
+    let _ = writeln!(
+        out,
+        "{possible} of {} games possible, IDs summing to {ids}; powers sum to {powers}",
+        games.len()
+    );
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::input::example;
+
+    #[test]
+    fn any_colours_fit_any_bag() {
+        let games = parse(&example(
+            "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+            Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+            Game 7: 2 sky blue, 1 red; 5 sky blue",
+        ))
+        .unwrap();
+        let puzzle = Cubes::puzzle_bag();
+        assert!(games[0].possible_with(&puzzle));
+        assert_eq!(games[1].minimal_bag().excess(&puzzle), [("red", 20, 12)]);
+        assert!(!games[2].possible_with(&puzzle));
+        assert_eq!(games[2].minimal_bag().to_string(), "1 red, 5 sky blue");
+        assert_eq!(games[1].minimal_bag().power(&puzzle), 1560);
+        // No blue at all is a power of 0.
+        let no_blue = Cubes::parse_bag("1 red, 3 green").unwrap();
+        assert_eq!(no_blue.power(&puzzle), 0);
+
+        let bag = Cubes::parse_bag("20 red, 13 green,\n6 blue\n5 sky blue\n").unwrap();
+        assert!(games.iter().all(|game| game.possible_with(&bag)));
+        assert_eq!(games[2].minimal_bag().power(&bag), 0);
+        let sky = Cubes::parse_bag("9 red, 9 sky blue").unwrap();
+        assert_eq!(games[2].minimal_bag().power(&sky), 5);
+        assert_eq!(
+            report(&games, &puzzle),
+            "bag: 14 blue, 13 green, 12 red\n\
+             Game 1: possible; minimal bag 6 blue, 2 green, 4 red, power 48\n\
+             Game 3: impossible, 20 red > 12; minimal bag 6 blue, 13 green, 20 red, power 1560\n\
+             Game 7: impossible, 5 sky blue > 0; minimal bag 1 red, 5 sky blue, power 0\n\
+             1 of 3 games possible, IDs summing to 1; powers sum to 1608\n"
+        );
+
+        let error = |input| parse(input).unwrap_err().to_string();
+        assert_eq!(
+            error("Game 1: 3 red, 4 red"),
+            "line 1, column 18: `red` more than once"
+        );
+        assert!(matches!(
+            parse("Game 1: 3 dark red, 4 dark red").unwrap_err(),
+            Error::RepeatedColour { colour, .. } if colour == "dark red"
+        ));
+        assert!(matches!(
+            parse("Game 1: 3 red; 4").unwrap_err(),
+            Error::Malformed(_)
+        ));
+        assert_eq!(
+            error("Game 1: 3 red; 4"),
+            "line 1, column 17: expected a colour"
+        );
+        assert_eq!(error("Game 8 1 red"), "line 1, column 7: expected `:`");
+        assert_eq!(error("Game 8x: 1 red"), "line 1, column 7: unexpected `x`");
+        assert_eq!(
+            Cubes::parse_bag("12 red\ngreen").unwrap_err().to_string(),
+            "line 2, column 1: expected an integer"
+        );
+    }
+}
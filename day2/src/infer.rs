@@ -0,0 +1,232 @@
+//! What the bag most likely held, given what a game showed.
+//!
+//! Each handful is taken as `n` cubes drawn without replacement from a bag
+//! of `total`, and put back before the next. So a bag with `c` cubes of each
+//! colour shows a handful `d` with the multivariate hypergeometric
+//! probability
+//!
+//! ```text
+//! C(c_1, d_1) * C(c_2, d_2) * ... / C(total, n)
+//! ```
+//!
+//! and a game's likelihood is the product over its handfuls. Every bag with
+//! the right total that could have shown the game is scored, and the best
+//! come back ranked. The share is each bag's part of the likelihood summed
+//! over all of them, which is its probability if every bag was equally
+//! likely to begin with.
+
+use crate::cubes::{Cubes, Game};
+
+/// More bags than this aren't ranked, as there would be no end to it.
+const MAX_BAGS: u64 = 2_000_000;
+
+/// Bags bigger than this aren't ranked either: scoring one needs `ln(k!)`
+/// for every `k` up to its total, 8 MB of them at this size.
+const MAX_TOTAL: u32 = 1_000_000;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Candidate {
+    pub bag: Cubes,
+    pub log_likelihood: f64,
+    pub share: f64,
+}
+
+impl Candidate {
+    pub fn likelihood(&self) -> f64 {
+        self.log_likelihood.exp()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ranking {
+    /// How many bags were scored.
+    pub considered: u64,
+    /// The most likely first.
+    pub candidates: Vec<Candidate>,
+}
+
+/// `ln(k!)` for every `k` up to `n`.
+fn ln_factorials(n: u32) -> Vec<f64> {
+    let mut table = vec![0.0; n as usize + 1];
+    for k in 1..table.len() {
+        table[k] = table[k - 1] + (k as f64).ln();
+    }
+    table
+}
+
+/// How many ways `spare` cubes go into `colours` colours, or `None` past
+/// [`MAX_BAGS`].
+fn bags(spare: u32, colours: usize) -> Option<u64> {
+    // C(spare + colours - 1, colours - 1), built up a factor at a time.
+    let mut count: u64 = 1;
+    for i in 1..colours as u64 {
+        count = count.checked_mul(u64::from(spare) + i)? / i;
+        if count > MAX_BAGS {
+            return None;
+        }
+    }
+    Some(count)
+}
+
+/// Ranks the bags of `total` cubes that `game` could have come from, keeping
+/// the `keep` most likely. The bag's colours are the ones the game showed
+/// and any of `colours` it didn't.
+pub fn infer(game: &Game, total: u32, colours: &[&str], keep: usize) -> Result<Ranking, String> {
+    if total > MAX_TOTAL {
+        return Err(format!(
+            "a bag of {total} cubes is too big to rank, the most is {MAX_TOTAL}"
+        ));
+    }
+    let minimal = game.minimal_bag();
+    let mut names: Vec<&str> = minimal.colours().map(|(colour, _)| colour).collect();
+    for colour in colours {
+        if !names.contains(colour) {
+            names.push(colour);
+        }
+    }
+    let at_least: Vec<u32> = names.iter().map(|colour| minimal.get(colour)).collect();
+    let needed: u32 = at_least.iter().sum();
+    if needed > total {
+        return Err(format!(
+            "game {} shows at least {needed} cubes, more than {total}",
+            game.id
+        ));
+    }
+    let Some(considered) = bags(total - needed, names.len()) else {
+        return Err(format!(
+            "too many bags of {total} in {} colours to rank",
+            names.len()
+        ));
+    };
+
+    let ln_fact = ln_factorials(total);
+    let ln_choose =
+        |n: u32, k: u32| ln_fact[n as usize] - ln_fact[k as usize] - ln_fact[(n - k) as usize];
+    let draws: Vec<Vec<u32>> = game
+        .draws
+        .iter()
+        .map(|draw| names.iter().map(|colour| draw.get(colour)).collect())
+        .collect();
+    // The same for every bag, but part of the likelihood all the same.
+    let handfuls: f64 = draws
+        .iter()
+        .map(|draw| ln_choose(total, draw.iter().sum()))
+        .sum();
+    let score = |bag: &[u32]| {
+        let ways: f64 = draws
+            .iter()
+            .flat_map(|draw| bag.iter().zip(draw).map(|(&c, &d)| ln_choose(c, d)))
+            .sum();
+        ways - handfuls
+    };
+
+    // Every bag, counting through the cubes past each colour's least like
+    // an odometer, the last colour taking whatever the others leave.
+    let (spare, last) = (total - needed, names.len() - 1);
+    let mut scored: Vec<(f64, Vec<u32>)> = Vec::with_capacity(considered as usize);
+    let mut extra = vec![0; names.len()];
+    'bags: loop {
+        extra[last] = spare - extra[..last].iter().sum::<u32>();
+        let bag: Vec<u32> = at_least.iter().zip(&extra).map(|(a, e)| a + e).collect();
+        scored.push((score(&bag), bag));
+        let mut i = last;
+        loop {
+            if i == 0 {
+                break 'bags;
+            }
+            i -= 1;
+            extra[i] += 1;
+            if extra[..last].iter().sum::<u32>() <= spare {
+                break;
+            }
+            extra[i] = 0;
+        }
+    }
+
+    let best = scored
+        .iter()
+        .map(|(score, _)| *score)
+        .fold(f64::NEG_INFINITY, f64::max);
+    let sum: f64 = scored.iter().map(|(score, _)| (score - best).exp()).sum();
+    scored.sort_by(|a, b| b.0.total_cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+    let candidates = scored
+        .into_iter()
+        .take(keep)
+        .map(|(log_likelihood, counts)| {
+            let mut bag = Cubes::default();
+            for (colour, count) in names.iter().zip(counts) {
+                bag.insert(colour, count);
+            }
+            Candidate {
+                bag,
+                log_likelihood,
+                share: (log_likelihood - best).exp() / sum,
+            }
+        })
+        .collect();
+    Ok(Ranking {
+        considered,
+        candidates,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::parse::Scanner;
+
+    #[test]
+    fn ranks_bags_by_likelihood() {
+        let game = |line| Game::parse(Scanner::new(line)).unwrap();
+
+        // From 4 cubes, 1 red and 1 blue comes up 4 ways in 6 with 2 of
+        // each, and 3 ways with 1 and 3 either way round.
+        let ranking = infer(&game("Game 1: 1 red, 1 blue"), 4, &[], 10).unwrap();
+        assert_eq!(ranking.considered, 3);
+        let best = &ranking.candidates[0];
+        assert_eq!(best.bag.to_string(), "2 blue, 2 red");
+        assert!((best.likelihood() - 4.0 / 6.0).abs() < 1e-12);
+        assert!((best.share - 0.4).abs() < 1e-12);
+        let second = &ranking.candidates[1];
+        assert_eq!(second.bag.to_string(), "1 blue, 3 red");
+        assert!((second.share - 0.3).abs() < 1e-12);
+
+        // Green was never seen, so the likeliest bag has none: 3 red show
+        // both handfuls for sure, and 2 red with a green only 1 time in 3
+        // and then 2 in 3.
+        let ranking = infer(&game("Game 2: 2 red; 1 red"), 3, &["red", "green"], 1).unwrap();
+        assert_eq!(ranking.considered, 2);
+        assert_eq!(ranking.candidates[0].bag.to_string(), "0 green, 3 red");
+        assert!((ranking.candidates[0].share - 9.0 / 11.0).abs() < 1e-12);
+
+        // Sums over every bag come to 1.
+        let ranking = infer(
+            &game("Game 3: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green"),
+            39,
+            &["red", "green", "blue"],
+            usize::MAX,
+        )
+        .unwrap();
+        // 27 spare cubes in 3 colours.
+        assert_eq!(ranking.considered, 29 * 28 / 2);
+        let total: f64 = ranking.candidates.iter().map(|c| c.share).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+
+        assert_eq!(
+            infer(&game("Game 4: 9 red, 9 blue"), 17, &[], 1).unwrap_err(),
+            "game 4 shows at least 18 cubes, more than 17"
+        );
+        assert!(infer(&game("Game 5: 1 a, 1 b, 1 c, 1 d, 1 e, 1 f"), 500, &[], 1).is_err());
+        // One colour is a single bag, but the table would still be 32 GB.
+        assert_eq!(
+            infer(&game("Game 6: 1 red"), 4_000_000_000, &[], 1).unwrap_err(),
+            "a bag of 4000000000 cubes is too big to rank, the most is 1000000"
+        );
+        assert_eq!(
+            infer(&game("Game 6: 1 red"), MAX_TOTAL, &[], 1)
+                .unwrap()
+                .considered,
+            1
+        );
+    }
+}
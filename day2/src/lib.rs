@@ -0,0 +1,7 @@
+//! Day 2: Cube Conundrum
+
+pub mod cubes;
+pub mod infer;
+pub mod part1;
+pub mod part2;
+pub mod stream;
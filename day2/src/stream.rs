@@ -0,0 +1,220 @@
+//! Both parts' answers for a game log of any size, read a line at a time.
+//!
+//! Nothing is kept of a game once it's counted but its ID, so a repeated ID
+//! can be caught. A line that can't be counted is skipped with a
+//! [`Diagnostic`] saying where and why, rather than ending the whole run,
+//! and the totals are of the games that could be.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{self, BufRead};
+
+use aoc::parse::{ParseError, Scanner};
+
+use crate::cubes::{self, Cubes, Game};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    /// Not a game line at all, or one with a bad count or colour.
+    Malformed,
+    /// A draw naming one colour twice.
+    RepeatedColour,
+    /// A game ID seen on an earlier line.
+    RepeatedGame,
+}
+
+impl Kind {
+    pub fn name(self) -> &'static str {
+        match self {
+            Kind::Malformed => "malformed",
+            Kind::RepeatedColour => "repeated-colour",
+            Kind::RepeatedGame => "repeated-game",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// 1-based, in the whole log.
+    pub line: usize,
+    /// 1-based, counted in chars.
+    pub column: usize,
+    pub kind: Kind,
+    pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}: {}",
+            self.line,
+            self.column,
+            self.kind.name(),
+            self.message
+        )
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Totals {
+    /// Games counted.
+    pub games: usize,
+    /// Lines skipped with a diagnostic.
+    pub skipped: usize,
+    /// Part 1: the IDs of the games possible with the bag, summed.
+    pub possible: u64,
+    /// Part 2: the powers of the games' minimal bags over the bag's colours,
+    /// summed.
+    pub power: u64,
+}
+
+/// The running totals, fed a line at a time.
+pub struct Tally {
+    bag: Cubes,
+    line: usize,
+    first_seen: HashMap<u32, usize>,
+    totals: Totals,
+}
+
+impl Tally {
+    pub fn new(bag: Cubes) -> Tally {
+        Tally {
+            bag,
+            line: 0,
+            first_seen: HashMap::new(),
+            totals: Totals::default(),
+        }
+    }
+
+    pub fn totals(&self) -> &Totals {
+        &self.totals
+    }
+
+    /// Counts the next line of the log, which shouldn't have its line break.
+    /// Blank lines are passed over.
+    pub fn line(&mut self, text: &str) -> Result<(), Diagnostic> {
+        self.line += 1;
+        let text = text.trim_end();
+        if text.is_empty() {
+            return Ok(());
+        }
+        self.count(text).inspect_err(|_| self.totals.skipped += 1)
+    }
+
+    fn count(&mut self, text: &str) -> Result<(), Diagnostic> {
+        let game = Game::parse(Scanner::new(text)).map_err(|error| {
+            let kind = match error {
+                cubes::Error::Malformed(_) => Kind::Malformed,
+                cubes::Error::RepeatedColour { .. } => Kind::RepeatedColour,
+            };
+            let at = ParseError::from(error);
+            Diagnostic {
+                line: self.line,
+                column: at.column,
+                kind,
+                message: at.message,
+            }
+        })?;
+        if let Some(&first) = self.first_seen.get(&game.id) {
+            // The ID is the first number on the line.
+            let at = text.find(|c: char| c.is_ascii_digit()).unwrap_or(0);
+            return Err(Diagnostic {
+                line: self.line,
+                column: text[..at].chars().count() + 1,
+                kind: Kind::RepeatedGame,
+                message: format!("game {} is already on line {first}", game.id),
+            });
+        }
+        self.first_seen.insert(game.id, self.line);
+
+        let minimal = game.minimal_bag();
+        if minimal.excess(&self.bag).is_empty() {
+            self.totals.possible += u64::from(game.id);
+        }
+        self.totals.power += minimal.power(&self.bag);
+        self.totals.games += 1;
+        Ok(())
+    }
+
+    /// Counts the next line as raw bytes, which had better be UTF-8.
+    pub fn bytes(&mut self, bytes: &[u8]) -> Result<(), Diagnostic> {
+        match std::str::from_utf8(bytes) {
+            Ok(text) => self.line(text),
+            Err(error) => {
+                self.line += 1;
+                self.totals.skipped += 1;
+                let valid = std::str::from_utf8(&bytes[..error.valid_up_to()]).unwrap_or("");
+                Err(Diagnostic {
+                    line: self.line,
+                    column: valid.chars().count() + 1,
+                    kind: Kind::Malformed,
+                    message: "not UTF-8".to_string(),
+                })
+            }
+        }
+    }
+}
+
+/// Reads a whole log from `reader`, handing each diagnostic to `report` as
+/// soon as its line has been read.
+pub fn tally(
+    mut reader: impl BufRead,
+    bag: Cubes,
+    mut report: impl FnMut(Diagnostic),
+) -> io::Result<Totals> {
+    let mut tally = Tally::new(bag);
+    let mut line = vec![];
+    loop {
+        line.clear();
+        if reader.read_until(b'\n', &mut line)? == 0 {
+            return Ok(tally.totals);
+        }
+        let text = line.strip_suffix(b"\n").unwrap_or(&line);
+        if let Err(diagnostic) = tally.bytes(text) {
+            report(diagnostic);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_what_it_can_and_says_why_not() {
+        let log: &[u8] = b"Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\r\n\
+                   Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue\n\
+                   \n\
+                   Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red\n\
+                   Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red\n\
+                   Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green\n\
+                   Game 6: 1 red, 2 red\n\
+                   Game  2: 1 red\n\
+                   Game 7: blue\n\
+                   Game 8 1 red\n\
+                   Game 9: 1 r\xc3\xa9d; 2 \xff\n";
+        let mut diagnostics = vec![];
+        let totals = tally(log, Cubes::puzzle_bag(), |d| diagnostics.push(d)).unwrap();
+        assert_eq!(
+            totals,
+            Totals {
+                games: 5,
+                skipped: 5,
+                possible: 8,
+                power: 2286,
+            }
+        );
+        let shown: Vec<String> = diagnostics.iter().map(|d| d.to_string()).collect();
+        assert_eq!(
+            shown,
+            [
+                "line 7, column 18: repeated-colour: `red` more than once",
+                "line 8, column 7: repeated-game: game 2 is already on line 2",
+                "line 9, column 9: malformed: expected an integer",
+                "line 10, column 7: malformed: expected `:`",
+                "line 11, column 18: malformed: not UTF-8",
+            ]
+        );
+    }
+}
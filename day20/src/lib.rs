@@ -0,0 +1,4 @@
+//! Day 20: Pulse Propagation
+
+pub mod part1;
+pub mod part2;
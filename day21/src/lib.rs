@@ -0,0 +1,4 @@
+//! Day 21: Step Counter
+
+pub mod part1;
+pub mod part2;
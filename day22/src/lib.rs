@@ -0,0 +1,4 @@
+//! Day 22: Sand Slabs
+
+pub mod part1;
+pub mod part2;
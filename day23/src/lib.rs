@@ -0,0 +1,4 @@
+//! Day 23: A Long Walk
+
+pub mod part1;
+pub mod part2;
@@ -0,0 +1,339 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use aoc::bits::BitGrid;
+use aoc::cancel::Cancel;
+use aoc::input::normalize;
+use aoc::parse::Scanner;
+use aoc::render::Palette;
+use aoc::trace::Tracer;
+
+/**
+--- Part Two ---
+
+As you reach the trailhead, you realize that the
+ground isn't as slippery as you expected; you'll
+have no problem climbing up the steep slopes.
+
+Now, treat all slopes as if they were normal paths (.).
+You still want to make sure you have the most scenic hike possible,
+so continue to ensure that you never step onto the same tile twice.
+What is the longest hike you can take?
+
+In the example above, this increases the longest hike to 154 steps:
+
+#S#####################
+#OOOOOOO#########OOO###
+#######O#########O#O###
+###OOOOO#.>OOO###O#O###
+###O#####.#O#O###O#O###
+###O>...#.#O#OOOOO#OOO#
+###O###.#.#O#########O#
+###OOO#.#.#OOOOOOO#OOO#
+#####O#.#.#######O#O###
+#OOOOO#.#.#OOOOOOO#OOO#
+#O#####.#.#O#########O#
+#O#OOO#...#OOO###...>O#
+#O#O#O#######O###.###O#
+#OOO#O>.#...>O>.#.###O#
+#####O#.#.###O#.#.###O#
+#OOOOO#...#OOO#.#.#OOO#
+#O#########O###.#.#O###
+#OOO###OOO#OOO#...#O###
+###O###O#O###O#####O###
+#OOO#OOO#O#OOO>.#.>O###
+#O###O###O#O###.#.#O###
+#OOOOO###OOO###...#OOO#
+#####################O#
+
+Find the longest hike you can take through the surprisingly dry hiking
+trails listed on your map. How many steps long is the longest hike?
+
+*/
+#[derive(PartialEq, Eq)]
+enum Tile {
+    Forest,
+    Path,
+}
+struct Maze {
+    maze: Vec<Vec<Tile>>,
+    x_size: usize,
+    y_size: usize,
+    cache: BTreeMap<(usize, usize), BitGrid>,
+    tracer: Tracer,
+}
+
+/// The maze with the best path found so far from `at` (`.`) and the tiles
+/// walked to get there (`,`).
+struct MazeView<'a> {
+    maze: &'a Vec<Vec<Tile>>,
+    at: Option<(usize, usize)>,
+    best: &'a BitGrid,
+    traversed: &'a BitGrid,
+}
+
+impl fmt::Display for MazeView<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.maze.iter().enumerate() {
+            for (x, tile) in row.iter().enumerate() {
+                let c = match tile {
+                    Tile::Forest => '█',
+                    Tile::Path if self.at == Some((x, y)) => 'O',
+                    Tile::Path if self.best.contains((x, y)) => '.',
+                    Tile::Path if self.traversed.contains((x, y)) => ',',
+                    Tile::Path => ' ',
+                };
+                write!(f, "{c}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Colours for `--render`: forest, the current best hike and the tiles the
+/// walk is on.
+pub fn palette() -> Palette {
+    Palette::new([225, 215, 185])
+        .tiles("█", [40, 90, 40])
+        .tiles(".", [255, 200, 80])
+        .tiles(",", [140, 140, 210])
+        .tiles("O", [230, 60, 60])
+        .path([220, 40, 40])
+}
+
+impl Maze {
+    fn new(input: &str) -> Maze {
+        let mut maze = Vec::new();
+        let mut x_size = 0;
+        let mut y_size = 0;
+
+        let tiles = Scanner::new(input)
+            .grid()
+            .expect("trail map should be rectangular");
+        for (y, line) in tiles.rows.iter().enumerate() {
+            y_size = y;
+            let mut row = Vec::new();
+            for (x, c) in line.chars().enumerate() {
+                x_size = x;
+                row.push(match c {
+                    '>' => Tile::Path,
+                    '<' => Tile::Path,
+                    'v' => Tile::Path,
+                    '^' => Tile::Path,
+                    '.' => Tile::Path,
+                    '#' => Tile::Forest,
+                    _ => unreachable!("Bad input: {c}"),
+                });
+            }
+            maze.push(row);
+        }
+
+        Maze {
+            maze,
+            x_size,
+            y_size,
+            cache: BTreeMap::new(),
+            tracer: Tracer::from_env(),
+        }
+    }
+
+    /// An empty set of tiles the size of the maze.
+    fn tiles(&self) -> BitGrid {
+        BitGrid::new(self.x_size + 1, self.y_size + 1)
+    }
+
+    fn walk_tile(
+        &mut self,
+        x: usize,
+        y: usize,
+        traversed: BitGrid,
+        cancel: &Cancel,
+    ) -> Option<BitGrid> {
+        if cancel.is_cancelled() {
+            return None;
+        }
+        if x == self.x_size - 1 && y == self.y_size {
+            dbg!(traversed.count(), &self.cache.len());
+            return Some(self.tiles());
+        }
+        if self.maze[y][x] != Tile::Path {
+            return None;
+        }
+        if traversed.contains((x, y)) {
+            return None;
+        }
+        if let Some(&ref cache) = self.cache.get(&(x, y)) {
+            if cache.count() > traversed.count() {
+                return None;
+            }
+            // return cache.clone();
+        }
+        let mut traversed = traversed;
+        traversed.insert((x, y));
+        let mut max = None;
+        if let Some(mut back_track) = self.walk_tile(x + 1, y, traversed.clone(), cancel) {
+            if back_track.insert((x + 1, y)) {
+                max = Some(back_track);
+            }
+        }
+        if let Some(mut back_track) = self.walk_tile(x, y + 1, traversed.clone(), cancel) {
+            if back_track.insert((x, y + 1)) {
+                if let Some(cur_max) = &max {
+                    if back_track.count() > cur_max.count() {
+                        max = Some(back_track);
+                    }
+                } else {
+                    max = Some(back_track);
+                }
+            }
+        }
+        if x > 0 {
+            if let Some(mut back_track) = self.walk_tile(x - 1, y, traversed.clone(), cancel) {
+                if back_track.insert((x - 1, y)) {
+                    if let Some(cur_max) = &max {
+                        if back_track.count() > cur_max.count() {
+                            max = Some(back_track);
+                        }
+                    } else {
+                        max = Some(back_track);
+                    }
+                }
+            }
+        }
+        if y > 0 {
+            if let Some(mut back_track) = self.walk_tile(x, y - 1, traversed.clone(), cancel) {
+                if back_track.insert((x, y - 1)) {
+                    if let Some(cur_max) = &max {
+                        if back_track.count() > cur_max.count() {
+                            max = Some(back_track);
+                        }
+                    } else {
+                        max = Some(back_track);
+                    }
+                }
+            }
+        }
+        if let Some(best) = &max {
+            self.tracer
+                .note(format!("best from ({x}, {y}) is {} long", best.count()));
+            self.tracer.state(MazeView {
+                maze: &self.maze,
+                at: Some((x, y)),
+                best,
+                traversed: &traversed,
+            });
+            // self.cache.insert((x, y), traversed.clone());
+        }
+
+        max
+    }
+
+    fn walk(&mut self, cancel: &Cancel) -> usize {
+        self.cache.clear();
+        let walk = self.walk_tile(1, 0, self.tiles(), cancel);
+
+        // for ((x, y), c) in &self.cache {
+        if let Some(cache) = walk {
+            dbg!(cache.count());
+            self.tracer.state(MazeView {
+                maze: &self.maze,
+                at: None,
+                best: &cache,
+                traversed: &self.tiles(),
+            });
+            // The set has no order, so follow it tile by tile from the start.
+            let mut hike: Vec<(usize, usize)> = vec![(1, 0)];
+            let mut left = cache.clone();
+            while let Some(&(x, y)) = hike.last() {
+                let next = [
+                    (x + 1, y),
+                    (x, y + 1),
+                    (x.wrapping_sub(1), y),
+                    (x, y.wrapping_sub(1)),
+                ]
+                .into_iter()
+                .find(|&tile| left.remove(tile));
+                match next {
+                    Some(tile) => hike.push(tile),
+                    None => break,
+                }
+            }
+            self.tracer.path(hike);
+            print!(
+                "{}",
+                MazeView {
+                    maze: &self.maze,
+                    at: None,
+                    best: &cache,
+                    traversed: &self.tiles(),
+                }
+            );
+        }
+        // }
+
+        0
+    }
+}
+
+pub fn process(input: &str, cancel: &Cancel) -> String {
+    let input = normalize(input);
+    let mut maze = Maze::new(&input);
+
+    return maze.walk(cancel).to_string();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::process;
+    use aoc::cancel::Cancel;
+
+    //     #[test]
+    //     fn it_works_small() {
+    //         let input = "#.####
+    // #....#
+    // #....#
+    // #....#
+    // ####.#";
+    //         let result = process(input, &Cancel::never());
+    //         assert_eq!(result, "_".to_string());
+    //     }
+//     #[test]
+//     fn it_works_small() {
+//         let input = "#.#####
+// #.....#
+// #.....#
+// #####.#";
+//         let result = process(input, &Cancel::never());
+//         assert_eq!(result, "_".to_string());
+//     }
+
+    #[test]
+    fn it_works() {
+        let input = "#.#####################
+#.......#########...###
+#######.#########.#.###
+###.....#.>.>.###.#.###
+###v#####.#v#.###.#.###
+###.>...#.#.#.....#...#
+###v###.#.#.#########.#
+###...#.#.#.......#...#
+#####.#.#.#######.#.###
+#.....#.#.#.......#...#
+#.#####.#.#.#########v#
+#.#...#...#...###...>.#
+#.#.#v#######v###.###v#
+#...#.>.#...>.>.#.###.#
+#####v#.#.###v#.#.###.#
+#.....#...#...#.#.#...#
+#.#########.###.#.#.###
+#...###...#...#...#.###
+###.###.#.###v#####v###
+#...#...#.#.>.>.#.>.###
+#.###.###.#.###.#.#v###
+#.....###...###...#...#
+#####################.#";
+        let result = process(input, &Cancel::never());
+        assert_eq!(result, "_".to_string());
+    }
+}
@@ -0,0 +1,4 @@
+//! Day 24: Never Tell Me The Odds
+
+pub mod part1;
+pub mod part2;
@@ -0,0 +1,225 @@
+use std::collections::{BTreeSet, HashMap};
+
+use aoc::exact::{intersect_2d, Intersection, Line2, Rational};
+use aoc::input::normalize;
+use aoc::parse::{self, Scanner};
+
+/**
+--- Day 24: Never Tell Me The Odds ---
+
+It seems like something is going wrong with the snow-making process.
+Instead of forming snow, the water that's been absorbed into the air
+seems to be forming hail!
+
+Maybe there's something you can do to break up the hailstones?
+
+Due to strong, probably-magical winds, the hailstones are all flying
+through the air in perfectly linear trajectories. You make a note of
+each hailstone's position and velocity (your puzzle input). For example:
+
+19, 13, 30 @ -2,  1, -2
+18, 19, 22 @ -1, -1, -2
+20, 25, 34 @ -2, -2, -4
+12, 31, 28 @ -1, -2, -1
+20, 19, 15 @  1, -5, -3
+
+Each line of text corresponds to the position and velocity of a single
+hailstone. The positions indicate where the hailstones are right now
+(at time 0). The velocities are constant and indicate exactly how far
+each hailstone will move in one nanosecond.
+
+Each line of text uses the format px py pz @ vx vy vz. For instance,
+the hailstone specified by 20, 19, 15 @ 1, -5, -3 has initial X position 20,
+Y position 19, Z position 15, X velocity 1, Y velocity -5, and Z velocity -3.
+After one nanosecond, the hailstone would be at 21, 14, 12.
+
+Perhaps you won't have to do anything. How likely are the hailstones
+to collide with each other and smash into tiny ice crystals?
+
+To estimate this, consider only the X and Y axes; ignore the Z axis.
+Looking forward in time, how many of the hailstones' paths will intersect
+within a test area? (The hailstones themselves don't have to collide,
+just test for intersections between the paths they will trace.)
+
+In this example, look for intersections that happen with an X and Y position
+each at least 7 and at most 27; in your actual data, you'll need to check a
+much larger test area. Comparing all pairs of hailstones' future
+paths produces the following results:
+
+Hailstone A: 19, 13, 30 @ -2, 1, -2
+Hailstone B: 18, 19, 22 @ -1, -1, -2
+Hailstones' paths will cross inside the test area (at x=14.333, y=15.333).
+
+Hailstone A: 19, 13, 30 @ -2, 1, -2
+Hailstone B: 20, 25, 34 @ -2, -2, -4
+Hailstones' paths will cross inside the test area (at x=11.667, y=16.667).
+
+Hailstone A: 19, 13, 30 @ -2, 1, -2
+Hailstone B: 12, 31, 28 @ -1, -2, -1
+Hailstones' paths will cross outside the test area (at x=6.2, y=19.4).
+
+Hailstone A: 19, 13, 30 @ -2, 1, -2
+Hailstone B: 20, 19, 15 @ 1, -5, -3
+Hailstones' paths crossed in the past for hailstone A.
+
+Hailstone A: 18, 19, 22 @ -1, -1, -2
+Hailstone B: 20, 25, 34 @ -2, -2, -4
+Hailstones' paths are parallel; they never intersect.
+
+Hailstone A: 18, 19, 22 @ -1, -1, -2
+Hailstone B: 12, 31, 28 @ -1, -2, -1
+Hailstones' paths will cross outside the test area (at x=-6, y=-5).
+
+Hailstone A: 18, 19, 22 @ -1, -1, -2
+Hailstone B: 20, 19, 15 @ 1, -5, -3
+Hailstones' paths crossed in the past for both hailstones.
+
+Hailstone A: 20, 25, 34 @ -2, -2, -4
+Hailstone B: 12, 31, 28 @ -1, -2, -1
+Hailstones' paths will cross outside the test area (at x=-2, y=3).
+
+Hailstone A: 20, 25, 34 @ -2, -2, -4
+Hailstone B: 20, 19, 15 @ 1, -5, -3
+Hailstones' paths crossed in the past for hailstone B.
+
+Hailstone A: 12, 31, 28 @ -1, -2, -1
+Hailstone B: 20, 19, 15 @ 1, -5, -3
+Hailstones' paths crossed in the past for both hailstones.
+
+So, in this example, 2 hailstones' future paths cross inside
+the boundaries of the test area.
+
+However, you'll need to search a much larger test area if you want to
+see if any hailstones might collide. Look for intersections that
+happen with an X and Y position each at least
+200000000000000 and at most 400000000000000. Disregard the Z axis entirely.
+
+Considering only the X and Y axes, check all pairs of hailstones'
+future paths for intersections. How many of these intersections
+occur within the test area?
+
+*/
+#[derive(Debug, Clone, Copy)]
+struct Vec3 {
+    x: i64,
+    y: i64,
+    z: i64,
+}
+
+impl Vec3 {
+    fn new(parts: Vec<i64>, line: Scanner) -> parse::Result<Vec3> {
+        match parts[..] {
+            [x, y, z] => Ok(Vec3 { x, y, z }),
+            _ => Err(line.error(format!("expected 3 coordinates, got {}", parts.len()))),
+        }
+    }
+}
+
+struct Hail {
+    // px py pz @ vx vy vz
+    position: Vec3,
+    velocity: Vec3,
+}
+
+#[derive(Debug)]
+struct Collision {
+    hail_a: usize,
+    hail_b: usize,
+    // time: f64,
+    collision: [Rational; 2],
+}
+
+impl Hail {
+    fn new(line: Scanner) -> parse::Result<Hail> {
+        let (mut position, mut velocity) = line.split_once("@")?;
+        let position = Vec3::new(position.ints::<i64>()?, line)?;
+        let velocity = Vec3::new(velocity.ints::<i64>()?, line)?;
+
+        Ok(Hail { position, velocity })
+    }
+
+    // Ignoring the Z axis.
+    fn path(&self) -> Line2 {
+        Line2 {
+            point: [self.position.x as i128, self.position.y as i128],
+            direction: [self.velocity.x as i128, self.velocity.y as i128],
+        }
+    }
+
+    fn get_collision(&self, other: &Hail, min: &Rational, max: &Rational) -> Option<[Rational; 2]> {
+        match intersect_2d(&self.path(), &other.path()) {
+            Intersection::Point { t, u, at } => {
+                if !t.is_positive() || !u.is_positive() {
+                    // println!("intersects before t:{t}, u:{u}");
+                    return None;
+                }
+                if at.iter().any(|v| v < min || v > max) {
+                    // println!("out of bounds x:{}, y:{}", at[0], at[1]);
+                    return None;
+                }
+                Some(at)
+            }
+            // Parallel paths never cross, and no two hailstones share a path.
+            _ => None,
+        }
+    }
+}
+
+struct Storm {
+    hail: Vec<Hail>,
+}
+
+impl Storm {
+    fn new(input: &str) -> parse::Result<Storm> {
+        Ok(Storm {
+            hail: Scanner::new(input)
+                .lines()
+                .map(Hail::new)
+                .collect::<parse::Result<_>>()?,
+        })
+    }
+
+    fn get_collisions(&mut self, min: i64, max: i64) -> Vec<Collision> {
+        let (min, max) = (Rational::from(min), Rational::from(max));
+        let mut collisions = vec![];
+        for i in 0..self.hail.len() - 1 {
+            for j in i + 1..self.hail.len() {
+                let hail_a = &self.hail[i];
+                let hail_b = &self.hail[j];
+                if let Some(collision) = hail_a.get_collision(hail_b, &min, &max) {
+                    collisions.push(Collision {
+                        hail_a: i,
+                        hail_b: j,
+                        collision,
+                    });
+                }
+            }
+        }
+        collisions
+    }
+}
+
+/// Every hailstone, read but not thrown.
+pub fn parse(input: &str) -> parse::Result<()> {
+    Storm::new(&normalize(input)).map(drop)
+}
+
+pub fn process(input: &str, min: i64, max: i64) -> String {
+    let input = normalize(input);
+    let mut storm = Storm::new(&input).expect("hailstones should parse");
+    let collisions = storm.get_collisions(min, max);
+    // dbg!(&collisions);
+    return collisions.len().to_string();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::process;
+
+    #[test]
+    fn it_works() {
+        let input = include_str!("bin/t1.txt");
+        let result = process(input, 7, 27);
+        assert_eq!(result, "2".to_string());
+    }
+}
@@ -0,0 +1,4 @@
+//! Day 25: Snowverload
+
+pub mod part1;
+pub mod part2;
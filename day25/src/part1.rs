@@ -0,0 +1,27 @@
+use std::collections::HashMap;
+
+use aoc::input::normalize;
+
+/**
+--- Day 25: Snowverload ---
+
+*/
+pub fn process(input: &str) -> String {
+    let input = normalize(input);
+    let mut lines = input.lines();
+
+    return 0.to_string();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::process;
+
+    #[test]
+    fn it_works() {
+        let result = process(
+            "",
+        );
+        assert_eq!(result, "".to_string());
+    }
+}
@@ -0,0 +1,24 @@
+use std::collections::HashMap;
+
+use aoc::input::normalize;
+
+/**
+
+*/
+pub fn process(input: &str) -> String {
+    let input = normalize(input);
+    let mut lines = input.lines();
+
+    return 0.to_string();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::process;
+
+    #[test]
+    fn it_works() {
+        let result = process("");
+        assert_eq!(result, "".to_string());
+    }
+}
@@ -0,0 +1,379 @@
+//! The schematic drawn back out with what the solver made of each cell, for
+//! seeing why an answer is off.
+//!
+//! The drawing is built from a parsed [`Schematic`] alone, so it shows what
+//! was read rather than what the input says. Every cell gets a [`Class`]:
+//! numbers are part numbers or loose, symbols a gear by some [`Rule`], one the
+//! rule looked at but turned down, or any other symbol. The same classes come
+//! out as ANSI colours for a terminal, or as HTML or SVG for a browser, with
+//! a legend and each gear's working if asked for.
+
+use std::fmt::Write;
+
+use crate::rules::{Combine, Overflow, Rule};
+use crate::schematic::Schematic;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Ansi,
+    Html,
+    Svg,
+}
+
+impl Format {
+    /// `ansi`, `html` or `svg`, or a file name ending in one of the last two.
+    pub fn from_name(name: &str) -> Option<Format> {
+        let extension = name.rsplit('.').next().unwrap_or(name);
+        match extension.to_ascii_lowercase().as_str() {
+            "ansi" => Some(Format::Ansi),
+            "html" | "htm" => Some(Format::Html),
+            "svg" => Some(Format::Svg),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Class {
+    Blank,
+    /// A digit of a number touching a symbol.
+    Part,
+    /// A digit of a number touching none.
+    Loose,
+    Gear,
+    /// A symbol the rule looks at that isn't a gear, like a `*` with one part.
+    NotGear,
+    Symbol,
+}
+
+const CLASSES: [Class; 6] = [
+    Class::Blank,
+    Class::Part,
+    Class::Loose,
+    Class::Gear,
+    Class::NotGear,
+    Class::Symbol,
+];
+
+impl Class {
+    fn name(self) -> &'static str {
+        match self {
+            Class::Blank => "blank",
+            Class::Part => "part",
+            Class::Loose => "loose",
+            Class::Gear => "gear",
+            Class::NotGear => "not-gear",
+            Class::Symbol => "symbol",
+        }
+    }
+
+    fn legend(self) -> &'static str {
+        match self {
+            Class::Blank => "blank",
+            Class::Part => "part number",
+            Class::Loose => "not a part number",
+            Class::Gear => "gear",
+            Class::NotGear => "not a gear",
+            Class::Symbol => "other symbol",
+        }
+    }
+
+    fn ansi(self) -> &'static str {
+        match self {
+            Class::Blank => "2",
+            Class::Part => "32",
+            Class::Loose => "1;31",
+            Class::Gear => "1;33",
+            Class::NotGear => "35",
+            Class::Symbol => "36",
+        }
+    }
+
+    fn colour(self) -> &'static str {
+        match self {
+            Class::Blank => "#666666",
+            Class::Part => "#2e9e44",
+            Class::Loose => "#d62828",
+            Class::Gear => "#e0a800",
+            Class::NotGear => "#b04fc8",
+            Class::Symbol => "#2a8fbd",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Options {
+    /// Names each class in its colour under the drawing.
+    pub legend: bool,
+    /// Follows each row with the working of the gears in it.
+    pub ratios: bool,
+}
+
+/// The schematic's rows as characters, each with its class.
+pub fn cells(schematic: &Schematic, rule: &Rule) -> Vec<Vec<(char, Class)>> {
+    let mut rows = vec![vec![('.', Class::Blank); schematic.width]; schematic.height];
+    for (n, number) in schematic.numbers.iter().enumerate() {
+        let class = match schematic.is_part(n) {
+            true => Class::Part,
+            false => Class::Loose,
+        };
+        // Padded back out to its width, for any leading zeros.
+        let digits = format!(
+            "{:0width$}",
+            number.value,
+            width = number.end - number.start
+        );
+        for (column, digit) in (number.start..).zip(digits.chars()) {
+            rows[number.row][column] = (digit, class);
+        }
+    }
+    for (s, symbol) in schematic.symbols.iter().enumerate() {
+        let class = if rule.matches(schematic, s) {
+            Class::Gear
+        } else if rule.symbols.is_empty() || rule.symbols.contains(&symbol.symbol) {
+            Class::NotGear
+        } else {
+            Class::Symbol
+        };
+        rows[symbol.row][symbol.column] = (symbol.symbol, class);
+    }
+    rows
+}
+
+/// Each row's gears worked out, like `* 16345 = 467 × 35`.
+fn workings(schematic: &Schematic, rule: &Rule) -> Result<Vec<Vec<String>>, Overflow> {
+    let mut rows = vec![vec![]; schematic.height];
+    for gear in rule.gears(schematic) {
+        let (s, value) = gear?;
+        let numbers: Vec<String> = schematic
+            .numbers_of(s)
+            .iter()
+            .map(|&n| schematic.numbers[n].value.to_string())
+            .collect();
+        let working = match rule.combine {
+            Combine::Product => numbers.join(" × "),
+            Combine::Sum => numbers.join(" + "),
+            Combine::Max => format!("max({})", numbers.join(", ")),
+        };
+        let symbol = schematic.symbols[s];
+        rows[symbol.row].push(format!("{} {value} = {working}", symbol.symbol));
+    }
+    Ok(rows)
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Runs of cells in a row that share a class.
+fn runs(row: &[(char, Class)]) -> impl Iterator<Item = (Class, String)> + '_ {
+    row.chunk_by(|a, b| a.1 == b.1)
+        .map(|run| (run[0].1, run.iter().map(|&(c, _)| c).collect()))
+}
+
+fn ansi(rows: &[Vec<(char, Class)>], notes: &[Vec<String>], options: Options) -> String {
+    let mut out = String::new();
+    for (row, notes) in rows.iter().zip(notes) {
+        for (class, text) in runs(row) {
+            let _ = write!(out, "\x1b[{}m{text}\x1b[0m", class.ansi());
+        }
+        if options.ratios && !notes.is_empty() {
+            let _ = write!(out, "  {}", notes.join("; "));
+        }
+        out.push('\n');
+    }
+    if options.legend {
+        let legend: Vec<String> = CLASSES[1..]
+            .iter()
+            .map(|class| format!("\x1b[{}m{}\x1b[0m", class.ansi(), class.legend()))
+            .collect();
+        let _ = writeln!(out, "\n{}", legend.join("  "));
+    }
+    out
+}
+
+fn html(rows: &[Vec<(char, Class)>], notes: &[Vec<String>], options: Options) -> String {
+    let mut out = String::from(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Engine schematic</title>\n<style>\n\
+         pre { font: 14px monospace; }\n.note { color: #888; }\n",
+    );
+    for class in CLASSES {
+        let _ = writeln!(out, ".{} {{ color: {}; }}", class.name(), class.colour());
+    }
+    out.push_str("</style>\n</head>\n<body>\n<pre>\n");
+    for (row, notes) in rows.iter().zip(notes) {
+        for (class, text) in runs(row) {
+            let _ = write!(
+                out,
+                "<span class=\"{}\">{}</span>",
+                class.name(),
+                escape(&text)
+            );
+        }
+        if options.ratios && !notes.is_empty() {
+            let note = escape(&notes.join("; "));
+            let _ = write!(out, "  <span class=\"note\">{note}</span>");
+        }
+        out.push('\n');
+    }
+    out.push_str("</pre>\n");
+    if options.legend {
+        out.push_str("<p>");
+        for class in &CLASSES[1..] {
+            let _ = write!(
+                out,
+                "<span class=\"{}\">{}</span> ",
+                class.name(),
+                class.legend()
+            );
+        }
+        out.push_str("</p>\n");
+    }
+    out.push_str("</body>\n</html>\n");
+    out
+}
+
+fn svg(rows: &[Vec<(char, Class)>], notes: &[Vec<String>], options: Options) -> String {
+    // A monospace character is about 0.6 of the font size across.
+    const SIZE: f64 = 14.0;
+    const ADVANCE: f64 = SIZE * 0.6;
+    const LINE: f64 = SIZE * 1.25;
+
+    let mut lines: Vec<String> = vec![];
+    let mut longest = 0;
+    for (row, notes) in rows.iter().zip(notes) {
+        let mut line = String::new();
+        let mut length = row.len();
+        for (class, text) in runs(row) {
+            let _ = write!(
+                line,
+                "<tspan fill=\"{}\">{}</tspan>",
+                class.colour(),
+                escape(&text)
+            );
+        }
+        if options.ratios && !notes.is_empty() {
+            let note = format!("  {}", notes.join("; "));
+            length += note.chars().count();
+            let _ = write!(line, "<tspan fill=\"#888888\">{}</tspan>", escape(&note));
+        }
+        longest = longest.max(length);
+        lines.push(line);
+    }
+    if options.legend {
+        let mut line = String::new();
+        let mut length = 0;
+        for class in &CLASSES[1..] {
+            length += class.legend().len() + 2;
+            let _ = write!(
+                line,
+                "<tspan fill=\"{}\">{}  </tspan>",
+                class.colour(),
+                class.legend()
+            );
+        }
+        longest = longest.max(length);
+        lines.push(String::new());
+        lines.push(line);
+    }
+
+    let width = (longest as f64 * ADVANCE).ceil() + 2.0 * ADVANCE;
+    let height = (lines.len() as f64 * LINE).ceil() + LINE;
+    let mut out = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\">\n\
+         <rect width=\"{width}\" height=\"{height}\" fill=\"#ffffff\"/>\n\
+         <g font-family=\"monospace\" font-size=\"{SIZE}\" xml:space=\"preserve\">\n"
+    );
+    for (i, line) in lines.iter().enumerate() {
+        let y = (i + 1) as f64 * LINE;
+        let _ = writeln!(out, "<text x=\"{ADVANCE}\" y=\"{y}\">{line}</text>");
+    }
+    out.push_str("</g>\n</svg>\n");
+    out
+}
+
+/// Fails when a gear is worth too much to work out.
+pub fn render(
+    schematic: &Schematic,
+    rule: &Rule,
+    format: Format,
+    options: Options,
+) -> Result<String, Overflow> {
+    let rows = cells(schematic, rule);
+    let notes = workings(schematic, rule)?;
+    Ok(match format {
+        Format::Ansi => ansi(&rows, &notes, options),
+        Format::Html => html(&rows, &notes, options),
+        Format::Svg => svg(&rows, &notes, options),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_cell_gets_its_class() {
+        let schematic = Schematic::parse(
+            "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+&007......",
+        )
+        .unwrap();
+        let rows = cells(&schematic, &Rule::gear());
+        let classes = |row: &[(char, Class)]| -> String {
+            row.iter()
+                .map(|&(_, class)| match class {
+                    Class::Blank => '.',
+                    Class::Part => 'p',
+                    Class::Loose => 'l',
+                    Class::Gear => 'G',
+                    Class::NotGear => 'n',
+                    Class::Symbol => 's',
+                })
+                .collect()
+        };
+        assert_eq!(classes(&rows[0]), "ppp..lll..");
+        assert_eq!(classes(&rows[4]), "pppn......");
+        assert_eq!(classes(&rows[8]), "...s.G....");
+        let last: String = rows[10].iter().map(|&(c, _)| c).collect();
+        assert_eq!(
+            (last.as_str(), classes(&rows[10]).as_str()),
+            ("&007......", "sppp......")
+        );
+
+        let options = Options {
+            legend: true,
+            ratios: true,
+        };
+        let ansi = render(&schematic, &Rule::gear(), Format::Ansi, options).unwrap();
+        assert!(ansi.starts_with("\x1b[32m467\x1b[0m\x1b[2m..\x1b[0m\x1b[1;31m114\x1b[0m"));
+        assert!(ansi.contains("\x1b[1;33m*\x1b[0m\x1b[2m......\x1b[0m  * 16345 = 467 × 35\n"));
+        assert!(ansi.contains("\x1b[35mnot a gear\x1b[0m"));
+
+        let html = render(&schematic, &Rule::gear(), Format::Html, Options::default()).unwrap();
+        assert!(html.contains("<span class=\"symbol\">&amp;</span><span class=\"part\">007</span>"));
+        assert!(!html.contains("16345"));
+
+        let rule = Rule::parse("combine=sum").unwrap();
+        let svg = render(&schematic, &rule, Format::Svg, options).unwrap();
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+        assert!(svg.contains("* 1353 = 755 + 598"));
+        assert_eq!(svg.matches("<text ").count(), 11 + 2);
+
+        assert_eq!(Format::from_name("out/day3.SVG"), Some(Format::Svg));
+        assert_eq!(Format::from_name("ansi"), Some(Format::Ansi));
+        assert_eq!(Format::from_name("day3.png"), None);
+    }
+}
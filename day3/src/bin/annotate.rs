@@ -0,0 +1,65 @@
+//! The schematic reprinted in colour: part numbers, loose numbers, gears and
+//! the symbols that aren't quite gears.
+//!
+//! cargo run --bin annotate -- [--format ansi|html|svg] [--rule <settings>] [--legend] [--ratios] [--out <file>] [<input>]
+//!
+//! The format goes by `--out`'s extension when not given, and is ANSI on
+//! standard output with neither. `--rule` picks the gears as for `gears`.
+//! `src/bin/input.txt` unless given a file.
+
+use std::fs;
+
+use aoc::args::{fail, read, Args};
+use day3::annotate::{self, Format, Options};
+use day3::rules::Rule;
+use day3::schematic::Schematic;
+
+const USAGE: &str = "usage: [--format ansi|html|svg] [--rule <settings>] [--legend] [--ratios] [--out <file>] [<input>]";
+
+fn main() {
+    let mut format = None;
+    let mut rule = Rule::gear();
+    let mut options = Options::default();
+    let mut out = None;
+    let mut input = None;
+    let mut args = Args::new(USAGE);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--format" => {
+                let name = args.value();
+                format = Some(
+                    Format::from_name(&name)
+                        .unwrap_or_else(|| fail(&format!("--format: unknown format `{name}`"))),
+                );
+            }
+            "--rule" => {
+                let text = args.value();
+                rule = Rule::parse(&text).unwrap_or_else(|e| fail(&format!("--rule: {e}")));
+            }
+            "--legend" => options.legend = true,
+            "--ratios" => options.ratios = true,
+            "--out" => out = Some(args.value()),
+            file if !file.starts_with("--") && input.is_none() => input = Some(read(file)),
+            _ => args.usage(),
+        }
+    }
+    let format = format
+        .or_else(|| {
+            out.as_deref().map(|file| {
+                Format::from_name(file)
+                    .unwrap_or_else(|| fail(&format!("no format for {file}, give --format")))
+            })
+        })
+        .unwrap_or(Format::Ansi);
+
+    let input = input.unwrap_or_else(|| include_str!("./input.txt").to_string());
+    let schematic = Schematic::parse(&input).unwrap_or_else(|e| fail(&e.to_string()));
+    let drawing = annotate::render(&schematic, &rule, format, options)
+        .unwrap_or_else(|e| fail(&e.to_string()));
+    match out {
+        Some(file) => {
+            fs::write(&file, drawing).unwrap_or_else(|e| fail(&format!("can't write {file}: {e}")))
+        }
+        None => print!("{drawing}"),
+    }
+}
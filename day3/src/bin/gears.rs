@@ -0,0 +1,63 @@
+//! Totals the gears in a schematic by the puzzle's rule or any other.
+//!
+//! cargo run --bin gears -- [--rule <settings>]... [--list] [<input>]
+//!
+//! Each `--rule` is `gear` or settings over it, like `"parts=3.. combine=sum"`;
+//! with none, just the puzzle's rule. `--list` prints every gear found too.
+//! `src/bin/input.txt` unless given a file.
+
+use aoc::args::{fail, read, Args};
+use day3::rules::Rule;
+use day3::schematic::Schematic;
+
+const USAGE: &str = "usage: [--rule <settings>]... [--list] [<input>]";
+
+fn main() {
+    let mut rules = vec![];
+    let mut list = false;
+    let mut input = None;
+    let mut args = Args::new(USAGE);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--rule" => {
+                let text = args.value();
+                let rule = Rule::parse(&text).unwrap_or_else(|e| fail(&format!("--rule: {e}")));
+                rules.push(rule);
+            }
+            "--list" => list = true,
+            file if !file.starts_with("--") && input.is_none() => input = Some(read(file)),
+            _ => args.usage(),
+        }
+    }
+    if rules.is_empty() {
+        rules.push(Rule::gear());
+    }
+
+    let input = input.unwrap_or_else(|| include_str!("./input.txt").to_string());
+    let schematic = Schematic::parse(&input).unwrap_or_else(|e| fail(&e.to_string()));
+    for rule in &rules {
+        let counted = rule
+            .gears(&schematic)
+            .collect::<Result<Vec<(usize, u64)>, _>>()
+            .and_then(|gears| Ok((rule.total(&schematic)?, gears)));
+        let (total, gears) = counted.unwrap_or_else(|e| fail(&format!("{rule}: {e}")));
+        println!("{rule}: {total} from {} gears", gears.len());
+        if list {
+            for (s, value) in gears {
+                let symbol = schematic.symbols[s];
+                let numbers: Vec<String> = schematic
+                    .numbers_of(s)
+                    .iter()
+                    .map(|&n| schematic.numbers[n].value.to_string())
+                    .collect();
+                println!(
+                    "  {} at row {}, column {}: {} -> {value}",
+                    symbol.symbol,
+                    symbol.row + 1,
+                    symbol.column + 1,
+                    numbers.join(", ")
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,7 @@
+//! Day 3: Gear Ratios
+
+pub mod annotate;
+pub mod part1;
+pub mod part2;
+pub mod rules;
+pub mod schematic;
@@ -0,0 +1,297 @@
+//! Which symbols count as gears, and what each is worth.
+//!
+//! A [`Rule`] names the symbols it looks at, how many numbers one has to
+//! touch, and how those numbers combine. The puzzle's gear is one rule,
+//! [`Rule::gear`]; variants are written as `key=value` settings over it, so
+//! `parts=3..` is a `*` touching three or more numbers, their product, and
+//! `symbols=any combine=sum` is every symbol touching exactly two, summed.
+
+use std::fmt;
+
+use aoc::parse::{self, Scanner};
+
+use crate::schematic::Schematic;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Count {
+    Exactly(usize),
+    AtLeast(usize),
+    AtMost(usize),
+}
+
+impl Count {
+    pub fn allows(self, parts: usize) -> bool {
+        match self {
+            Count::Exactly(n) => parts == n,
+            Count::AtLeast(n) => parts >= n,
+            Count::AtMost(n) => parts <= n,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Combine {
+    Product,
+    Sum,
+    Max,
+}
+
+/// A gear, or all of them together, worth more than a `u64` holds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Overflow {
+    /// The gear at `row` and `column`, counting from 0.
+    Gear {
+        symbol: char,
+        row: usize,
+        column: usize,
+    },
+    Total,
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Overflow::Gear {
+                symbol,
+                row,
+                column,
+            } => write!(
+                f,
+                "the {symbol} at row {}, column {} is worth more than a u64 holds",
+                row + 1,
+                column + 1
+            ),
+            Overflow::Total => write!(f, "the gears add up to more than a u64 holds"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rule {
+    /// The symbols that can be gears; empty for any symbol.
+    pub symbols: Vec<char>,
+    pub count: Count,
+    pub combine: Combine,
+}
+
+impl Rule {
+    /// The puzzle's: a `*` touching exactly two numbers, its ratio their
+    /// product.
+    pub fn gear() -> Rule {
+        Rule {
+            symbols: vec!['*'],
+            count: Count::Exactly(2),
+            combine: Combine::Product,
+        }
+    }
+
+    /// [`Rule::gear`], which can be named as `gear`, changed by `key=value`
+    /// settings: `symbols=*#` or `symbols=any`, `parts=2`, `parts=2..` or
+    /// `parts=..3`, and `combine=product`, `sum` or `max`.
+    pub fn parse(text: &str) -> parse::Result<Rule> {
+        let mut rule = Rule::gear();
+        let mut settings = Scanner::new(text);
+        loop {
+            settings.skip_spaces();
+            if settings.is_empty() {
+                return Ok(rule);
+            }
+            let setting = settings.token()?;
+            if setting == "gear" {
+                rule = Rule::gear();
+                continue;
+            }
+            let Some((key, value)) = setting.split_once('=') else {
+                return Err(
+                    settings.error_at(setting, format!("expected `key=value`, not `{setting}`"))
+                );
+            };
+            let bad =
+                |what: &str| settings.error_at(value, format!("expected {what}, not `{value}`"));
+            match key {
+                "symbols" if value == "any" => rule.symbols.clear(),
+                "symbols" if !value.is_empty() => rule.symbols = value.chars().collect(),
+                "symbols" => return Err(bad("symbols or `any`")),
+                "parts" => {
+                    let number = |n: &str| {
+                        n.parse()
+                            .map_err(|_| bad("a count like `2`, `2..` or `..2`"))
+                    };
+                    rule.count = if let Some(n) = value.strip_suffix("..") {
+                        Count::AtLeast(number(n)?)
+                    } else if let Some(n) = value.strip_prefix("..") {
+                        Count::AtMost(number(n)?)
+                    } else {
+                        Count::Exactly(number(value)?)
+                    };
+                }
+                "combine" => {
+                    rule.combine = match value {
+                        "product" => Combine::Product,
+                        "sum" => Combine::Sum,
+                        "max" => Combine::Max,
+                        _ => return Err(bad("`product`, `sum` or `max`")),
+                    }
+                }
+                _ => {
+                    return Err(settings.error_at(
+                        key,
+                        format!(
+                            "unknown setting `{key}`, expected `symbols`, `parts` or `combine`"
+                        ),
+                    ))
+                }
+            }
+        }
+    }
+
+    /// Whether symbol `s` is a gear by this rule.
+    pub fn matches(&self, schematic: &Schematic, s: usize) -> bool {
+        let symbol = schematic.symbols[s].symbol;
+        (self.symbols.is_empty() || self.symbols.contains(&symbol))
+            && self.count.allows(schematic.numbers_of(s).len())
+    }
+
+    /// What symbol `s`'s numbers come to. A symbol touching none is worth 0
+    /// whatever they're combined with.
+    pub fn value(&self, schematic: &Schematic, s: usize) -> Result<u64, Overflow> {
+        if schematic.numbers_of(s).is_empty() {
+            return Ok(0);
+        }
+        let mut values = schematic
+            .numbers_of(s)
+            .iter()
+            .map(|&n| schematic.numbers[n].value);
+        let value = match self.combine {
+            Combine::Product => values.try_fold(1u64, |product, n| product.checked_mul(n)),
+            Combine::Sum => values.try_fold(0u64, |sum, n| sum.checked_add(n)),
+            Combine::Max => values.max(),
+        };
+        let symbol = schematic.symbols[s];
+        value.ok_or(Overflow::Gear {
+            symbol: symbol.symbol,
+            row: symbol.row,
+            column: symbol.column,
+        })
+    }
+
+    /// Each gear, by symbol index, with its value.
+    pub fn gears<'a>(
+        &'a self,
+        schematic: &'a Schematic,
+    ) -> impl Iterator<Item = Result<(usize, u64), Overflow>> + 'a {
+        (0..schematic.symbols.len())
+            .filter(|&s| self.matches(schematic, s))
+            .map(|s| Ok((s, self.value(schematic, s)?)))
+    }
+
+    pub fn total(&self, schematic: &Schematic) -> Result<u64, Overflow> {
+        self.gears(schematic).try_fold(0u64, |total, gear| {
+            total.checked_add(gear?.1).ok_or(Overflow::Total)
+        })
+    }
+}
+
+/// Settings that [`Rule::parse`] reads back as the same rule.
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.symbols.is_empty() {
+            write!(f, "symbols=any")?;
+        } else {
+            write!(f, "symbols={}", self.symbols.iter().collect::<String>())?;
+        }
+        match self.count {
+            Count::Exactly(n) => write!(f, " parts={n}")?,
+            Count::AtLeast(n) => write!(f, " parts={n}..")?,
+            Count::AtMost(n) => write!(f, " parts=..{n}")?,
+        }
+        let combine = match self.combine {
+            Combine::Product => "product",
+            Combine::Sum => "sum",
+            Combine::Max => "max",
+        };
+        write!(f, " combine={combine}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_puzzle_gear_is_one_rule_of_many() {
+        let schematic = Schematic::parse(
+            "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..
+..........
+12*3.....4
+..5......#",
+        )
+        .unwrap();
+        // The star touching 12, 3 and 5 isn't a gear, though it touches two.
+        assert_eq!(Rule::gear().total(&schematic), Ok(16345 + 451490));
+        let rule = |text| Rule::parse(text).unwrap();
+        assert_eq!(rule("gear"), Rule::gear());
+        assert_eq!(rule("parts=3..").total(&schematic), Ok(12 * 3 * 5));
+        assert_eq!(rule("parts=..1 combine=max").total(&schematic), Ok(617));
+        assert_eq!(
+            rule("symbols=any combine=sum").total(&schematic),
+            Ok(467 + 35 + 755 + 598)
+        );
+        assert_eq!(
+            rule("symbols=#$ parts=1").total(&schematic),
+            Ok(633 + 664 + 4)
+        );
+
+        // Numbers go up to u64::MAX, so a gear or the total can outgrow one.
+        let big = Schematic::parse(
+            "18446744073709551615*2\n\
+             ......................\n\
+             18446744073709551615*1",
+        )
+        .unwrap();
+        assert_eq!(
+            Rule::gear().total(&big),
+            Err(Overflow::Gear {
+                symbol: '*',
+                row: 0,
+                column: 20
+            })
+        );
+        assert_eq!(
+            Rule::gear().total(&big).unwrap_err().to_string(),
+            "the * at row 1, column 21 is worth more than a u64 holds"
+        );
+        assert_eq!(rule("combine=max").total(&big), Err(Overflow::Total));
+        assert_eq!(
+            rule("combine=max").gears(&big).last(),
+            Some(Ok((1, u64::MAX)))
+        );
+
+        let variant = rule("symbols=*# parts=..3 combine=sum");
+        assert_eq!(variant.to_string(), "symbols=*# parts=..3 combine=sum");
+        assert_eq!(rule(&variant.to_string()), variant);
+
+        let error = |text| Rule::parse(text).unwrap_err().to_string();
+        assert_eq!(
+            error("parts=2 combine=mean"),
+            "line 1, column 17: expected `product`, `sum` or `max`, not `mean`"
+        );
+        assert_eq!(
+            error("parts=two"),
+            "line 1, column 7: expected a count like `2`, `2..` or `..2`, not `two`"
+        );
+        assert_eq!(
+            error("colour=red"),
+            "line 1, column 1: unknown setting `colour`, expected `symbols`, `parts` or `combine`"
+        );
+    }
+}
@@ -0,0 +1,205 @@
+//! An engine schematic read once into its numbers and symbols, and which of
+//! them touch.
+//!
+//! A number touches a symbol when the symbol is in the ring of cells around
+//! the number's span, diagonals included. The touching pairs make a
+//! bipartite graph, kept as an adjacency list from each side, so part
+//! numbers are the numbers with any edge and gears are symbols with the
+//! right number of them.
+
+use std::collections::HashMap;
+
+use aoc::input::normalize;
+use aoc::parse::{self, Scanner};
+
+/// A number at columns `start..end` of `row`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Number {
+    pub value: u64,
+    pub row: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Symbol {
+    pub symbol: char,
+    pub row: usize,
+    pub column: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Schematic {
+    pub numbers: Vec<Number>,
+    pub symbols: Vec<Symbol>,
+    /// The widest row, in chars.
+    pub width: usize,
+    pub height: usize,
+    /// For each number, the symbols it touches, by index.
+    number_symbols: Vec<Vec<usize>>,
+    /// For each symbol, the numbers it touches, by index.
+    symbol_numbers: Vec<Vec<usize>>,
+}
+
+impl Schematic {
+    /// Reads a schematic: runs of digits are numbers, and anything else but
+    /// `.` is a symbol.
+    pub fn parse(input: &str) -> parse::Result<Schematic> {
+        let input = normalize(input);
+        let mut numbers = vec![];
+        let mut symbols = vec![];
+        let mut width = 0;
+        let mut height = 0;
+        for (row, mut line) in Scanner::new(&input).lines().enumerate() {
+            let mut column = 0;
+            while !line.is_empty() {
+                let digits = line.take_while(|c| c.is_ascii_digit());
+                if !digits.is_empty() {
+                    let value = digits
+                        .parse()
+                        .map_err(|_| line.error_at(digits, format!("`{digits}` is too large")))?;
+                    numbers.push(Number {
+                        value,
+                        row,
+                        start: column,
+                        end: column + digits.len(),
+                    });
+                    column += digits.len();
+                } else if let Some(c) = line.next_char() {
+                    if c != '.' {
+                        symbols.push(Symbol {
+                            symbol: c,
+                            row,
+                            column,
+                        });
+                    }
+                    column += 1;
+                }
+            }
+            width = width.max(column);
+            height = row + 1;
+        }
+
+        let at: HashMap<(usize, usize), usize> = symbols
+            .iter()
+            .enumerate()
+            .map(|(i, s)| ((s.row, s.column), i))
+            .collect();
+        let mut number_symbols = vec![vec![]; numbers.len()];
+        let mut symbol_numbers = vec![vec![]; symbols.len()];
+        for (n, number) in numbers.iter().enumerate() {
+            for row in number.row.saturating_sub(1)..=number.row + 1 {
+                for column in number.start.saturating_sub(1)..=number.end {
+                    if let Some(&s) = at.get(&(row, column)) {
+                        number_symbols[n].push(s);
+                        symbol_numbers[s].push(n);
+                    }
+                }
+            }
+        }
+        Ok(Schematic {
+            numbers,
+            symbols,
+            width,
+            height,
+            number_symbols,
+            symbol_numbers,
+        })
+    }
+
+    /// The symbols number `n` touches.
+    pub fn symbols_of(&self, n: usize) -> &[usize] {
+        &self.number_symbols[n]
+    }
+
+    /// The numbers symbol `s` touches.
+    pub fn numbers_of(&self, s: usize) -> &[usize] {
+        &self.symbol_numbers[s]
+    }
+
+    /// Whether number `n` touches any symbol.
+    pub fn is_part(&self, n: usize) -> bool {
+        !self.number_symbols[n].is_empty()
+    }
+
+    /// The numbers touching a symbol, by index.
+    pub fn part_numbers(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.numbers.len()).filter(|&n| self.is_part(n))
+    }
+
+    pub fn part_number_sum(&self) -> u64 {
+        self.part_numbers().map(|n| self.numbers[n].value).sum()
+    }
+
+    /// The numbers touching no symbol at all.
+    pub fn isolated(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.numbers.len()).filter(|&n| !self.is_part(n))
+    }
+
+    /// The `symbol`s touching exactly `parts` numbers, by index.
+    pub fn gears(&self, symbol: char, parts: usize) -> impl Iterator<Item = usize> + '_ {
+        (0..self.symbols.len())
+            .filter(move |&s| self.symbols[s].symbol == symbol && self.numbers_of(s).len() == parts)
+    }
+
+    /// Every number touching a `symbol`, once each, in reading order.
+    pub fn numbers_next_to(&self, symbol: char) -> Vec<usize> {
+        (0..self.numbers.len())
+            .filter(|&n| {
+                self.symbols_of(n)
+                    .iter()
+                    .any(|&s| self.symbols[s].symbol == symbol)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn numbers_and_symbols_meet_in_the_graph() {
+        let schematic = Schematic::parse(
+            "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..",
+        )
+        .unwrap();
+        assert_eq!((schematic.width, schematic.height), (10, 10));
+        assert_eq!(schematic.numbers.len(), 10);
+        assert_eq!(schematic.part_number_sum(), 4361);
+
+        let values =
+            |ns: &[usize]| -> Vec<u64> { ns.iter().map(|&n| schematic.numbers[n].value).collect() };
+        let isolated: Vec<usize> = schematic.isolated().collect();
+        assert_eq!(values(&isolated), [114, 58]);
+        let gears: Vec<usize> = schematic.gears('*', 2).collect();
+        assert_eq!(gears.len(), 2);
+        assert_eq!(values(schematic.numbers_of(gears[0])), [467, 35]);
+        assert_eq!(schematic.gears('*', 1).count(), 1);
+        assert_eq!(
+            values(&schematic.numbers_next_to('*')),
+            [467, 35, 617, 755, 598]
+        );
+        assert_eq!(values(&schematic.numbers_next_to('$')), [664]);
+
+        let zeros = Schematic::parse("007#\n..é.").unwrap();
+        assert_eq!(zeros.numbers[0].end, 3);
+        assert_eq!(zeros.symbols[1].column, 2);
+        assert_eq!(zeros.part_number_sum(), 7);
+        assert_eq!(
+            Schematic::parse("..\n.99999999999999999999")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 2: `99999999999999999999` is too large"
+        );
+    }
+}
@@ -0,0 +1,46 @@
+//! Part 2's pile of scratchcards, with where each card's copies came from.
+//!
+//! cargo run --bin cascade -- [--policy fail|clamp] [--provenance] [<input>]
+//!
+//! `fail`, the default, stops at a card winning cards past the end of the
+//! table; `clamp` wins what there is and says how much was lost.
+//! `--provenance` prints a line per card of where its copies came from.
+//! `src/bin/input.txt` unless given a file.
+
+use aoc::args::{fail, read, Args};
+use day4::cascade::{self, Policy};
+
+const USAGE: &str = "usage: [--policy fail|clamp] [--provenance] [<input>]";
+
+fn main() {
+    let mut policy = Policy::Fail;
+    let mut provenance = false;
+    let mut input = None;
+    let mut args = Args::new(USAGE);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--policy" => {
+                policy = match args.next().as_deref() {
+                    Some("fail") => Policy::Fail,
+                    Some("clamp") => Policy::Clamp,
+                    _ => args.usage(),
+                }
+            }
+            "--provenance" => provenance = true,
+            file if !file.starts_with("--") && input.is_none() => input = Some(read(file)),
+            _ => args.usage(),
+        }
+    }
+
+    let input = input.unwrap_or_else(|| include_str!("./input.txt").to_string());
+    let cards = cascade::parse(&input).unwrap_or_else(|e| fail(&e.to_string()));
+    let pile =
+        cascade::cascade(&cards, policy, provenance).unwrap_or_else(|e| fail(&e.to_string()));
+    if provenance {
+        print!("{}", cascade::report(&cards, &pile));
+    }
+    match pile.total() {
+        Some(total) => println!("{total} scratchcards"),
+        None => fail("too many scratchcards to count"),
+    }
+}
@@ -0,0 +1,278 @@
+//! Part 2's pile of won scratchcards, counted without going past the table
+//! or overflowing, and if asked with where every copy came from.
+//!
+//! A card's two lists are each a `u128` with bit `n` set for number `n`, so
+//! its matches are one `&` and a popcount. Copies are counted in `u128` too,
+//! which still overflows eventually since a pile can double with each card;
+//! that's an error rather than a wrong answer. A card winning more cards than
+//! are left below it is handled by [`Policy`].
+
+use std::fmt;
+
+use aoc::input::normalize;
+use aoc::parse::{self, Scanner};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Card {
+    pub id: u32,
+    pub winning: u128,
+    pub have: u128,
+}
+
+/// Numbers on a card, which have to be below 128 to fit a set.
+fn set(mut list: Scanner) -> parse::Result<u128> {
+    let mut set = 0;
+    loop {
+        list.skip_spaces();
+        if list.is_empty() {
+            return Ok(set);
+        }
+        let token = list.token()?;
+        match token.parse::<u32>() {
+            Ok(n) if n < u128::BITS => set |= 1 << n,
+            Ok(_) => return Err(list.error_at(token, format!("`{token}` is over 127"))),
+            Err(_) => return Err(list.error_at(token, format!("expected a number, not `{token}`"))),
+        }
+    }
+}
+
+impl Card {
+    /// Reads a `Card 1: 41 48 | 83 86 6` line. A number repeated in a list
+    /// counts once.
+    pub fn parse(line: Scanner) -> parse::Result<Card> {
+        let (mut card, numbers) = line.header()?;
+        card.tag("Card")?;
+        let id = card.int()?;
+        card.finish()?;
+        let (winning, have) = numbers.split_once("|")?;
+        Ok(Card {
+            id,
+            winning: set(winning)?,
+            have: set(have)?,
+        })
+    }
+
+    /// How many of the numbers had are winning ones.
+    pub fn matches(&self) -> u32 {
+        (self.winning & self.have).count_ones()
+    }
+
+    /// Part 1's score: 1 for the first match, doubled for each after. A set
+    /// holds at most 128 numbers, so this is at most 2^127.
+    pub fn points(&self) -> u128 {
+        match self.matches() {
+            0 => 0,
+            n => 1 << (n - 1),
+        }
+    }
+}
+
+/// Part 1: every card's points, or `None` if they add up past a `u128`.
+pub fn points(cards: &[Card]) -> Option<u128> {
+    cards
+        .iter()
+        .try_fold(0u128, |sum, card| sum.checked_add(card.points()))
+}
+
+pub fn parse(input: &str) -> parse::Result<Vec<Card>> {
+    let input = normalize(input);
+    Scanner::new(&input).lines().map(Card::parse).collect()
+}
+
+/// What to do about a card that wins more cards than there are below it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Policy {
+    /// Wins the cards there are, and notes the rest in [`Cascade::clamped`].
+    Clamp,
+    /// Stops with [`Error::OutOfRange`].
+    Fail,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// Card `id` won `matches` cards with only `left` below it.
+    OutOfRange { id: u32, matches: u32, left: usize },
+    /// More copies of card `id` than a `u128` holds.
+    Overflow { id: u32 },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Error::OutOfRange { id, matches, left } => write!(
+                f,
+                "card {id} wins {matches} cards but only {left} come after it"
+            ),
+            Error::Overflow { id } => write!(f, "too many copies of card {id} to count"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cascade {
+    /// Each card's instances, the original included, by position.
+    pub counts: Vec<u128>,
+    /// For each card, the copies of it won by each card above, as
+    /// `(position, copies)` from the nearest up. Only kept if asked for.
+    pub sources: Option<Vec<Vec<(usize, u128)>>>,
+    /// Under [`Policy::Clamp`], the cards that won past the end, by position,
+    /// with how many of their wins were lost.
+    pub clamped: Vec<(usize, u32)>,
+}
+
+impl Cascade {
+    pub fn total(&self) -> Option<u128> {
+        self.counts
+            .iter()
+            .try_fold(0u128, |sum, &n| sum.checked_add(n))
+    }
+}
+
+/// Plays out the cards top to bottom: every instance of a card with `m`
+/// matches wins one copy each of the `m` cards after it. With `provenance`,
+/// notes where each card's copies came from too.
+pub fn cascade(cards: &[Card], policy: Policy, provenance: bool) -> Result<Cascade, Error> {
+    let mut counts = vec![1u128; cards.len()];
+    let mut sources = provenance.then(|| vec![vec![]; cards.len()]);
+    let mut clamped = vec![];
+    for (i, card) in cards.iter().enumerate() {
+        let matches = card.matches();
+        let left = cards.len() - i - 1;
+        if matches as usize > left {
+            match policy {
+                Policy::Clamp => clamped.push((i, matches - left as u32)),
+                Policy::Fail => {
+                    return Err(Error::OutOfRange {
+                        id: card.id,
+                        matches,
+                        left,
+                    })
+                }
+            }
+        }
+        let copies = counts[i];
+        for j in i + 1..=i + left.min(matches as usize) {
+            counts[j] = counts[j]
+                .checked_add(copies)
+                .ok_or(Error::Overflow { id: cards[j].id })?;
+            if let Some(sources) = &mut sources {
+                sources[j].push((i, copies));
+            }
+        }
+    }
+    for from in sources.iter_mut().flatten() {
+        from.reverse();
+    }
+    Ok(Cascade {
+        counts,
+        sources,
+        clamped,
+    })
+}
+
+/// A line per card of how many there were and where the copies came from,
+/// like `Card 4: 8 (1 original, 4 from card 3, 2 from card 2, 1 from card 1)`,
+/// or just `Card 4: 8` if the cascade didn't keep that.
+pub fn report(cards: &[Card], cascade: &Cascade) -> String {
+    let mut out = String::new();
+    for (i, card) in cards.iter().enumerate() {
+        out.push_str(&format!("Card {}: {}", card.id, cascade.counts[i]));
+        if let Some(sources) = &cascade.sources {
+            let mut parts = vec!["1 original".to_string()];
+            parts.extend(
+                sources[i]
+                    .iter()
+                    .map(|&(from, copies)| format!("{copies} from card {}", cards[from].id)),
+            );
+            out.push_str(&format!(" ({})", parts.join(", ")));
+        }
+        out.push('\n');
+    }
+    for &(i, lost) in &cascade.clamped {
+        out.push_str(&format!(
+            "card {} won {lost} more past the end\n",
+            cards[i].id
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn copies_know_where_they_came_from() {
+        let example = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+        let cards = parse(example).unwrap();
+        assert_eq!(points(&cards), Some(13));
+
+        let pile = cascade(&cards, Policy::Fail, true).unwrap();
+        assert_eq!(pile.counts, [1, 2, 4, 8, 14, 1]);
+        assert_eq!(pile.total(), Some(30));
+        assert_eq!(pile.sources.as_ref().unwrap()[4], [(3, 8), (2, 4), (0, 1)]);
+        assert!(report(&cards, &pile)
+            .contains("Card 4: 8 (1 original, 4 from card 3, 2 from card 2, 1 from card 1)\n"));
+        let bare = cascade(&cards, Policy::Fail, false).unwrap();
+        assert_eq!((&bare.counts, &bare.sources), (&pile.counts, &None));
+        assert!(report(&cards, &bare).contains("\nCard 4: 8\n"));
+
+        // The last card matching 1 has nothing below it to win.
+        let past = parse("Card 1: 1 2 | 2\nCard 2: 5 7 | 7 8").unwrap();
+        assert_eq!(
+            cascade(&past, Policy::Fail, false).unwrap_err().to_string(),
+            "card 2 wins 1 cards but only 0 come after it"
+        );
+        let clamped = cascade(&past, Policy::Clamp, false).unwrap();
+        assert_eq!(
+            (clamped.counts, clamped.clamped),
+            (vec![1, 2], vec![(1, 1)])
+        );
+
+        // Each card winning the next 127 doubles the pile up to card 128's
+        // 2^127, card 129 comes to 2^128 - 1 and card 130 to more than that.
+        let deep: Vec<Card> = (1..=130)
+            .map(|id| Card {
+                id,
+                winning: u128::MAX >> 1,
+                have: u128::MAX,
+            })
+            .collect();
+        assert_eq!(
+            cascade(&deep[..129], Policy::Clamp, false).unwrap().counts[128],
+            u128::MAX
+        );
+        assert_eq!(
+            cascade(&deep, Policy::Clamp, false),
+            Err(Error::Overflow { id: 130 })
+        );
+
+        // Past 64 matches the points only fit a u128, and two cards of 128
+        // don't fit that.
+        assert_eq!(deep[60].points(), 1 << 126);
+        let seventy = Card {
+            id: 1,
+            winning: (1 << 70) - 1,
+            have: u128::MAX,
+        };
+        assert_eq!(seventy.points(), 1 << 69);
+        let all = Card {
+            id: 1,
+            winning: u128::MAX,
+            have: u128::MAX,
+        };
+        assert_eq!(all.points(), 1 << 127);
+        assert_eq!(points(&[seventy, all]), Some((1 << 69) + (1 << 127)));
+        assert_eq!(points(&[all, all]), None);
+
+        assert_eq!(
+            parse("Card 1: 41 128 | 83").unwrap_err().to_string(),
+            "line 1, column 12: `128` is over 127"
+        );
+    }
+}
@@ -0,0 +1,5 @@
+//! Day 4: Scratchcards
+
+pub mod cascade;
+pub mod part1;
+pub mod part2;
@@ -0,0 +1,4 @@
+//! Day 5: If You Give A Seed A Fertilizer
+
+pub mod part1;
+pub mod part2;
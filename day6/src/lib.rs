@@ -0,0 +1,4 @@
+//! Day 6: Wait For It
+
+pub mod part1;
+pub mod part2;
@@ -0,0 +1,149 @@
+use aoc::exact::isqrt;
+use aoc::input::normalize;
+use aoc::parse::{self, Scanner};
+
+/**
+--- Part Two ---
+
+As the race is about to start, you realize the piece of paper with race times and record distances you got earlier actually just has very bad kerning.
+ There's really only one race - ignore the spaces between the numbers on each line.
+
+So, the example from before:
+
+Time:      7  15   30
+Distance:  9  40  200
+
+...now instead means this:
+
+Time:      71530
+Distance:  940200
+
+Now, you have to figure out how many ways there are to win this single race. In this example, 
+the race lasts for 71530 milliseconds and the record distance you need to beat is 940200 millimeters. 
+You could hold the button anywhere from 14 to 71516 milliseconds and beat the record, a total of 71503 ways!
+
+How many ways can you beat the record in this one much longer race?
+
+ */
+fn get_ranges(time: u64, distance: u64) -> u64 {
+    let t = time as u128;
+    let d = distance as u128;
+    // t = t_1 + t_2
+    // v = t_1
+    // d = t_2 * v
+    // so holding for h wins when h * (t - h) > d,
+    // strictly between the roots of h^2 - t*h + d = 0.
+    if t * t <= 4 * d {
+        return 0;
+    }
+    let root = isqrt(t * t - 4 * d);
+
+    // The floored root puts this at most one short of the first winning hold.
+    let mut start = (t - root) / 2;
+    if start * (t - start) <= d {
+        start += 1;
+    }
+    // Winning holds are symmetric around t / 2.
+    let end = t - start;
+    // Only a hold past t / 2 could win, so none does.
+    if start > end {
+        return 0;
+    }
+
+    dbg!(start);
+    dbg!(end);
+
+    (end - start + 1) as u64
+}
+
+fn get_numbers(line: Scanner) -> parse::Result<Vec<u64>> {
+    let (_, mut values) = line.header()?;
+    values.skip_spaces();
+    let start = values.rest();
+    // The spaces between the numbers are just bad kerning, so the digits are
+    // joined up as written, leading zeros and all, and read as one number.
+    let mut digits = String::new();
+    loop {
+        values.skip_spaces();
+        if values.is_empty() {
+            break;
+        }
+        let token = values.token()?;
+        if !token.chars().all(|c| c.is_ascii_digit()) {
+            return Err(values.error_at(token, format!("expected digits, not `{token}`")));
+        }
+        digits.push_str(token);
+    }
+    if digits.is_empty() {
+        return Err(values.error("expected a number"));
+    }
+    let number = digits
+        .parse()
+        .map_err(|_| values.error_at(start, format!("`{digits}` is out of range")))?;
+
+    Ok(vec![number])
+}
+
+fn get_races(input: &str) -> parse::Result<(Vec<u64>, Vec<u64>)> {
+    let mut lines = Scanner::new(input).lines();
+    let Some(times) = lines.next() else {
+        return Err(Scanner::new(input).error("expected a line of times"));
+    };
+    let times = get_numbers(times)?;
+    let Some(distances) = lines.next() else {
+        return Err(Scanner::new(input).error_at(&input[input.len()..], "expected a line of distances"));
+    };
+    let line = distances;
+    let distances = get_numbers(distances)?;
+    if distances.len() != times.len() {
+        return Err(line.error(format!(
+            "expected {} distances, one for each time, not {}",
+            times.len(),
+            distances.len()
+        )));
+    }
+
+    Ok((times, distances))
+}
+
+/// The races' times and distances, read but not raced.
+pub fn parse(input: &str) -> parse::Result<()> {
+    get_races(&normalize(input)).map(drop)
+}
+
+pub fn part2(input: &str) -> String {
+    let input = normalize(input);
+    let (times, distances) = get_races(&input).expect("races should parse");
+
+    let product = (0..times.len())
+        .map(|i| get_ranges(times[i], distances[i]))
+        .inspect(|possible| println!("p: {possible}"))
+        .fold(1, |acc, x| acc * x);
+
+    return product.to_string();
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc::parse::Scanner;
+
+    use super::{get_numbers, part2};
+
+    #[test]
+    fn it_works() {
+        let result = part2(
+            "Time:      7  15   30
+Distance:  9  40  200",
+        );
+        assert_eq!(result, "71503".to_string());
+        // Holding 1 or 2 only ties the record.
+        assert_eq!(part2("Time: 3\nDistance: 2\n"), "0".to_string());
+
+        assert_eq!(get_numbers(Scanner::new("Time: 7 05")), Ok(vec![705]));
+        let error = get_numbers(Scanner::new("Time:  18446744 07370955 1616")).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 1, column 8: `18446744073709551616` is out of range"
+        );
+    }
+}
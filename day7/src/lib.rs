@@ -0,0 +1,4 @@
+//! Day 7: Camel Cards
+
+pub mod part1;
+pub mod part2;
@@ -0,0 +1,4 @@
+//! Day 8: Haunted Wasteland
+
+pub mod part1;
+pub mod part2;
@@ -0,0 +1,4 @@
+//! Day 9: Mirage Maintenance
+
+pub mod part1;
+pub mod part2;
@@ -0,0 +1,242 @@
+//! Benchmark history, so a change that makes a day slower gets noticed.
+//!
+//! `runner bench` solves each part a few times and appends one line per part
+//! to a tab-separated history file:
+//!
+//! ```text
+//! run  commit  day  part  runs  median ns  allocations
+//! ```
+//!
+//! `run` is when the bench started, in milliseconds since the Unix epoch, and
+//! ties together the parts measured in one go. `commit` is the commit checked
+//! out at the time, or `-` when there isn't one. `runner bench compare` lines
+//! the latest run up against an earlier one.
+
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    pub run: u64,
+    pub commit: String,
+    pub day: u8,
+    pub part: u8,
+    /// How many times the part was solved.
+    pub runs: usize,
+    pub median: Duration,
+    /// Median allocations and reallocations per solve.
+    pub allocations: usize,
+}
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            self.run,
+            self.commit,
+            self.day,
+            self.part,
+            self.runs,
+            self.median.as_nanos(),
+            self.allocations
+        )
+    }
+}
+
+impl Record {
+    fn parse(line: &str) -> Option<Record> {
+        let mut fields = line.split('\t');
+        let mut next = || fields.next();
+        Some(Record {
+            run: next()?.parse().ok()?,
+            commit: next()?.to_string(),
+            day: next()?.parse().ok()?,
+            part: next()?.parse().ok()?,
+            runs: next()?.parse().ok()?,
+            median: Duration::from_nanos(next()?.parse().ok()?),
+            allocations: next()?.parse().ok()?,
+        })
+    }
+}
+
+/// The middle sample, or the upper of the two middle ones.
+pub fn median<T: Ord + Copy>(samples: &mut [T]) -> T {
+    samples.sort_unstable();
+    samples[samples.len() / 2]
+}
+
+pub struct History {
+    path: PathBuf,
+    records: Vec<Record>,
+}
+
+impl History {
+    /// Reads the history at `path`. A missing file is an empty history.
+    pub fn open(path: &Path) -> Result<History, String> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(format!("can't read {}: {e}", path.display())),
+        };
+        Ok(History {
+            path: path.to_path_buf(),
+            records: text.lines().filter_map(Record::parse).collect(),
+        })
+    }
+
+    /// Adds a record to the end of the file, leaving the lines before it alone.
+    pub fn append(&mut self, record: Record) -> Result<(), String> {
+        let error = |e: io::Error| format!("can't write {}: {e}", self.path.display());
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .and_then(|mut file| writeln!(file, "{record}"))
+            .map_err(error)?;
+        self.records.push(record);
+        Ok(())
+    }
+
+    /// The parts measured in the latest run, and in the run they're compared
+    /// against: the latest earlier run whose commit starts with `baseline`, or
+    /// whose run is `baseline`, or else just the one before.
+    pub fn latest_and_baseline(
+        &self,
+        baseline: Option<&str>,
+    ) -> Result<(Vec<&Record>, Vec<&Record>), String> {
+        let run = |id: u64| -> Vec<&Record> {
+            self.records
+                .iter()
+                .filter(|record| record.run == id)
+                .collect()
+        };
+        let latest = self
+            .records
+            .iter()
+            .map(|record| record.run)
+            .max()
+            .ok_or_else(|| format!("{} has no benchmarks yet", self.path.display()))?;
+        let earlier = self
+            .records
+            .iter()
+            .filter(|record| record.run < latest)
+            .filter(|record| {
+                baseline.is_none_or(|baseline| {
+                    record.commit.starts_with(baseline) || record.run.to_string() == baseline
+                })
+            })
+            .map(|record| record.run)
+            .max()
+            .ok_or_else(|| match baseline {
+                Some(baseline) => format!("no run before the latest matches `{baseline}`"),
+                None => "there is only one run to compare".to_string(),
+            })?;
+        Ok((run(latest), run(earlier)))
+    }
+}
+
+/// How much bigger `after` is than `before`, in percent.
+pub fn change(before: f64, after: f64) -> f64 {
+    if before == 0.0 {
+        if after == 0.0 {
+            0.0
+        } else {
+            f64::INFINITY
+        }
+    } else {
+        (after - before) / before * 100.0
+    }
+}
+
+/// The commit checked out in `repo`, read straight from its `.git` directory.
+pub fn head_commit(repo: &Path) -> Option<String> {
+    let mut git = repo.join(".git");
+    // In a worktree `.git` is a file pointing at the real directory.
+    if let Ok(link) = fs::read_to_string(&git) {
+        git = repo.join(link.strip_prefix("gitdir:")?.trim());
+    }
+    // A worktree keeps its branches in the main repository.
+    let common = match fs::read_to_string(git.join("commondir")) {
+        Ok(dir) => git.join(dir.trim()),
+        Err(_) => git.clone(),
+    };
+
+    let head = fs::read_to_string(git.join("HEAD")).ok()?;
+    let Some(name) = head.trim().strip_prefix("ref:").map(str::trim) else {
+        // Detached, so HEAD holds the commit itself.
+        return Some(head.trim().to_string());
+    };
+    for dir in [&git, &common] {
+        if let Ok(commit) = fs::read_to_string(dir.join(name)) {
+            return Some(commit.trim().to_string());
+        }
+    }
+    // Branches that haven't moved since `git gc` only show up packed.
+    let packed = fs::read_to_string(common.join("packed-refs")).ok()?;
+    packed.lines().find_map(|line| {
+        let (commit, packed_name) = line.split_once(' ')?;
+        (packed_name == name).then(|| commit.to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(run: u64, commit: &str, day: u8, millis: u64) -> Record {
+        Record {
+            run,
+            commit: commit.to_string(),
+            day,
+            part: 1,
+            runs: 5,
+            median: Duration::from_millis(millis),
+            allocations: 100,
+        }
+    }
+
+    #[test]
+    fn runs_are_appended_and_compared() {
+        let dir = std::env::temp_dir().join(format!("runner-bench-{}", std::process::id()));
+        fs::create_dir_all(dir.join(".git/refs/heads")).unwrap();
+        fs::write(dir.join(".git/HEAD"), "ref: refs/heads/main\n").unwrap();
+        fs::write(
+            dir.join(".git/packed-refs"),
+            "# pack-refs\nabc123 refs/heads/main\n",
+        )
+        .unwrap();
+        assert_eq!(head_commit(&dir).as_deref(), Some("abc123"));
+        fs::write(dir.join(".git/refs/heads/main"), "def456\n").unwrap();
+        assert_eq!(head_commit(&dir).as_deref(), Some("def456"));
+
+        let path = dir.join("history.tsv");
+        let mut history = History::open(&path).unwrap();
+        assert!(history.latest_and_baseline(None).is_err());
+        for record in [
+            record(1, "abc123", 1, 10),
+            record(1, "abc123", 2, 10),
+            record(2, "def456", 1, 10),
+            record(3, "def456", 1, 12),
+            record(3, "def456", 2, 20),
+        ] {
+            history.append(record).unwrap();
+        }
+
+        let history = History::open(&path).unwrap();
+        assert_eq!(history.records.len(), 5);
+        let (latest, baseline) = history.latest_and_baseline(None).unwrap();
+        assert_eq!((latest.len(), baseline.len()), (2, 1));
+        assert_eq!(baseline[0].run, 2);
+        let (_, baseline) = history.latest_and_baseline(Some("abc")).unwrap();
+        assert_eq!(baseline[1].median, Duration::from_millis(10));
+        assert!(history.latest_and_baseline(Some("fff")).is_err());
+
+        assert_eq!(change(10.0, 12.0).round(), 20.0);
+        assert_eq!(median(&mut [3, 1, 2, 9]), 3);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
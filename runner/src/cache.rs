@@ -0,0 +1,225 @@
+//! Answers from earlier runs, so slow parts aren't solved again for nothing.
+//!
+//! An answer is reused when the day, the part, the solver's version and a hash
+//! of the normalized input all match. The cache is a tab-separated file with
+//! one answer per line:
+//!
+//! ```text
+//! day  part  version  input hash  stored at  elapsed ns  answer
+//! ```
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::solvers::Solver;
+
+/// 64-bit FNV-1a. Stable across Rust releases, unlike the std hasher.
+pub fn hash(input: &str) -> u64 {
+    input.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Key {
+    pub day: u8,
+    pub part: u8,
+    pub version: String,
+    pub input: u64,
+}
+
+impl Key {
+    pub fn new(solver: &Solver, input: &str) -> Key {
+        Key {
+            day: solver.day,
+            part: solver.part,
+            version: solver.version.to_string(),
+            input: hash(&aoc::input::normalize(input)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub key: Key,
+    pub answer: String,
+    /// How long solving took when the answer was stored.
+    pub elapsed: Duration,
+    /// Seconds since the Unix epoch.
+    pub stored: u64,
+}
+
+impl fmt::Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\t{:016x}\t{}\t{}\t{}",
+            self.key.day,
+            self.key.part,
+            escape(&self.key.version),
+            self.key.input,
+            self.stored,
+            self.elapsed.as_nanos(),
+            escape(&self.answer)
+        )
+    }
+}
+
+impl Entry {
+    fn parse(line: &str) -> Option<Entry> {
+        let mut fields = line.split('\t');
+        let mut next = || fields.next();
+        let day = next()?.parse().ok()?;
+        let part = next()?.parse().ok()?;
+        let version = unescape(next()?);
+        let input = u64::from_str_radix(next()?, 16).ok()?;
+        let stored = next()?.parse().ok()?;
+        let elapsed = Duration::from_nanos(next()?.parse().ok()?);
+        let answer = unescape(next()?);
+        Some(Entry {
+            key: Key {
+                day,
+                part,
+                version,
+                input,
+            },
+            answer,
+            elapsed,
+            stored,
+        })
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+}
+
+fn unescape(field: &str) -> String {
+    let mut out = String::with_capacity(field.len());
+    let mut chars = field.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        out.push(match chars.next() {
+            Some('t') => '\t',
+            Some('n') => '\n',
+            Some(c) => c,
+            None => '\\',
+        });
+    }
+    out
+}
+
+pub struct Cache {
+    path: PathBuf,
+    entries: Vec<Entry>,
+}
+
+impl Cache {
+    /// Reads the cache at `path`. A missing file is an empty cache.
+    pub fn open(path: &Path) -> Result<Cache, String> {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(format!("can't read {}: {e}", path.display())),
+        };
+        Ok(Cache {
+            path: path.to_path_buf(),
+            // A line that doesn't parse is dropped the next time the cache is saved.
+            entries: text.lines().filter_map(Entry::parse).collect(),
+        })
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    pub fn get(&self, key: &Key) -> Option<&Entry> {
+        self.entries.iter().find(|entry| entry.key == *key)
+    }
+
+    /// Stores an answer, replacing any answer already stored for `key`.
+    pub fn insert(&mut self, key: Key, answer: String, elapsed: Duration) {
+        let stored = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |since| since.as_secs());
+        self.entries.retain(|entry| entry.key != key);
+        self.entries.push(Entry {
+            key,
+            answer,
+            elapsed,
+            stored,
+        });
+    }
+
+    /// Drops the entries `keep` says no to and returns how many went.
+    pub fn retain(&mut self, keep: impl FnMut(&Entry) -> bool) -> usize {
+        let before = self.entries.len();
+        self.entries.retain(keep);
+        before - self.entries.len()
+    }
+
+    pub fn save(&self) -> Result<(), String> {
+        let error = |e: io::Error| format!("can't write {}: {e}", self.path.display());
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(error)?;
+        }
+        let text: String = self
+            .entries
+            .iter()
+            .map(|entry| format!("{entry}\n"))
+            .collect();
+        // Written aside and renamed so an interrupted run can't leave half a file.
+        let partial = self.path.with_extension("partial");
+        fs::write(&partial, text).map_err(error)?;
+        fs::rename(&partial, &self.path).map_err(error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_survive_a_round_trip() {
+        let path = std::env::temp_dir().join(format!("runner-cache-{}.tsv", std::process::id()));
+        let key = Key {
+            day: 23,
+            part: 2,
+            version: "1".to_string(),
+            input: hash("#.#\n..."),
+        };
+
+        let mut cache = Cache::open(&path).unwrap();
+        assert!(cache.get(&key).is_none());
+        cache.insert(key.clone(), "0".to_string(), Duration::from_secs(90));
+        cache.insert(key.clone(), "6298".to_string(), Duration::from_secs(80));
+        cache.save().unwrap();
+
+        let mut cache = Cache::open(&path).unwrap();
+        assert_eq!(cache.entries().len(), 1);
+        let entry = cache.get(&key).unwrap();
+        assert_eq!(
+            (entry.answer.as_str(), entry.elapsed),
+            ("6298", Duration::from_secs(80))
+        );
+
+        let odd = "a\tb\\n\nc";
+        assert_eq!(unescape(&escape(odd)), odd);
+
+        let bumped = Key {
+            version: "2".to_string(),
+            ..key
+        };
+        assert!(cache.get(&bumped).is_none());
+        assert_eq!(cache.retain(|entry| entry.key.version == "2"), 1);
+        fs::remove_file(path).unwrap();
+    }
+}
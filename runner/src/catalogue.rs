@@ -0,0 +1,311 @@
+//! The puzzle statements, read back out of the solvers' source.
+//!
+//! Each `dayN/src/partP.rs` opens with its part of the puzzle text in a
+//! `/** */` comment, headed by a `--- Day N: Title ---` or `--- Part Two ---`
+//! banner, and ends with tests that run the examples from that text. The
+//! statement is the comment with its banner and ` * ` gutter taken off, and
+//! the examples are the inputs those tests pass in and the answers they
+//! expect. Tests still expecting `""` or `_` aren't examples yet.
+
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    pub answer: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Part {
+    pub part: u8,
+    /// Empty when the puzzle text hasn't been copied in.
+    pub text: String,
+    pub examples: Vec<Example>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Puzzle {
+    pub day: u8,
+    pub title: Option<String>,
+    pub parts: Vec<Part>,
+}
+
+impl Puzzle {
+    /// `Day 22: Sand Slabs`, or just `Day 22` without a title.
+    pub fn heading(&self) -> String {
+        match &self.title {
+            Some(title) => format!("Day {}: {title}", self.day),
+            None => format!("Day {}", self.day),
+        }
+    }
+
+    pub fn part(&self, part: u8) -> Option<&Part> {
+        self.parts.iter().find(|p| p.part == part)
+    }
+}
+
+/// Every day with a solver source under `repo`, in order.
+pub fn load(repo: &Path) -> Vec<Puzzle> {
+    (1..=25).filter_map(|day| load_day(repo, day)).collect()
+}
+
+/// `None` when neither part of `day` has a source file.
+pub fn load_day(repo: &Path, day: u8) -> Option<Puzzle> {
+    let src = repo.join(format!("day{day}")).join("src");
+    let mut title = None;
+    let mut parts = vec![];
+    for part in [1, 2] {
+        let Ok(source) = fs::read_to_string(src.join(format!("part{part}.rs"))) else {
+            continue;
+        };
+        let (banner, text) = statement(&source);
+        title = title.or(banner);
+        let examples = examples(&source, |path| fs::read_to_string(src.join(path)).ok());
+        parts.push(Part {
+            part,
+            text,
+            examples,
+        });
+    }
+    (!parts.is_empty()).then_some(Puzzle { day, title, parts })
+}
+
+/// The title from a `--- Day N: Title ---` banner, and the rest of the first
+/// doc comment in `source`.
+fn statement(source: &str) -> (Option<String>, String) {
+    let Some(start) = source.find("/**") else {
+        return (None, String::new());
+    };
+    let body = &source[start + 3..];
+    let body = &body[..body.find("*/").unwrap_or(body.len())];
+
+    let mut title = None;
+    let mut lines: Vec<&str> = vec![];
+    for line in body.lines() {
+        let trimmed = line.trim_start();
+        let line = if trimmed == "*" {
+            ""
+        } else {
+            trimmed.strip_prefix("* ").unwrap_or(line).trim_end()
+        };
+        if let Some(banner) = line
+            .trim()
+            .strip_prefix("---")
+            .and_then(|banner| banner.strip_suffix("---"))
+        {
+            if let Some((_, name)) = banner.split_once(':') {
+                title = Some(name.trim().to_string());
+            }
+            continue;
+        }
+        // One blank line between paragraphs is plenty.
+        if !line.is_empty() || lines.last().is_some_and(|last| !last.is_empty()) {
+            lines.push(line);
+        }
+    }
+    while lines.last() == Some(&"") {
+        lines.pop();
+    }
+    (title, lines.join("\n"))
+}
+
+/// Reads the string literal at the start of `code`, returning its value and
+/// the code after it.
+fn literal(code: &str) -> Option<(String, &str)> {
+    let raw = code.strip_prefix('r');
+    if let Some(raw) = raw {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        let body = raw[hashes..].strip_prefix('"')?;
+        let end = format!("\"{}", "#".repeat(hashes));
+        let close = body.find(&end)?;
+        return Some((body[..close].to_string(), &body[close + end.len()..]));
+    }
+
+    let mut value = String::new();
+    let mut chars = code.strip_prefix('"')?.char_indices();
+    while let Some((i, c)) = chars.next() {
+        match c {
+            '"' => return Some((value, &code[1 + i + 1..])),
+            '\\' => match chars.next()?.1 {
+                'n' => value.push('\n'),
+                't' => value.push('\t'),
+                'r' => value.push('\r'),
+                '0' => value.push('\0'),
+                '\n' => {
+                    // A line continuation skips the next line's indent too.
+                    let rest = chars.as_str();
+                    let indent = rest.len() - rest.trim_start().len();
+                    for _ in rest[..indent].chars() {
+                        chars.next();
+                    }
+                }
+                'u' => {
+                    let rest = chars.as_str();
+                    let close = rest.find('}')?;
+                    let code = u32::from_str_radix(&rest[1..close], 16).ok()?;
+                    value.push(char::from_u32(code)?);
+                    for _ in rest[..=close].chars() {
+                        chars.next();
+                    }
+                }
+                other => value.push(other),
+            },
+            c => value.push(c),
+        }
+    }
+    None
+}
+
+/// The examples the tests in `source` run: each `assert_eq!(result, "...")`
+/// paired with the string literal, or `include_str!` file read through
+/// `include`, that came before it.
+fn examples(source: &str, include: impl Fn(&str) -> Option<String>) -> Vec<Example> {
+    let Some(start) = source.find("#[cfg(test)]") else {
+        return vec![];
+    };
+    let mut code = &source[start..];
+    let mut input = None;
+    let mut examples = vec![];
+    while let Some(c) = code.chars().next() {
+        if code.starts_with("//") {
+            code = code.find('\n').map_or("", |end| &code[end..]);
+        } else if code.starts_with("'\"'") {
+            code = &code[3..];
+        } else if let Some(rest) = code.strip_prefix("include_str!(") {
+            if let Some((path, rest)) = literal(rest.trim_start()) {
+                input = include(&path);
+                code = rest;
+            } else {
+                code = rest;
+            }
+        } else if let Some(rest) = code.strip_prefix("assert_eq!(result,") {
+            let Some((answer, rest)) = literal(rest.trim_start()) else {
+                code = rest;
+                continue;
+            };
+            if let Some(input) = input.take().filter(|_| !matches!(&answer[..], "" | "_")) {
+                // Tests indent their inputs to line up with the code.
+                let input = aoc::input::example(&input);
+                examples.push(Example { input, answer });
+            }
+            code = rest;
+        } else if let Some((value, rest)) = literal(code) {
+            input = Some(value);
+            code = rest;
+        } else {
+            // Skips whole words, so the `r` ending one isn't read as a raw string.
+            let word = code
+                .find(|c: char| !c.is_alphanumeric() && c != '_')
+                .unwrap_or(code.len());
+            code = &code[word.max(c.len_utf8())..];
+        }
+    }
+    examples
+}
+
+/// Breaks lines longer than `width` at spaces, keeping their indent. Lines
+/// without a space, like the rows of a grid, are left whole.
+pub fn wrap(text: &str, width: usize) -> String {
+    let mut out = String::new();
+    for line in text.lines() {
+        if line.len() <= width {
+            out.push_str(line);
+            out.push('\n');
+            continue;
+        }
+        let indent = &line[..line.len() - line.trim_start().len()];
+        let mut current = indent.to_string();
+        for word in line.split_whitespace() {
+            if current.len() > indent.len() && current.len() + 1 + word.len() > width {
+                out.push_str(&current);
+                out.push('\n');
+                current = indent.to_string();
+            }
+            if current.len() > indent.len() {
+                current.push(' ');
+            }
+            current.push_str(word);
+        }
+        out.push_str(&current);
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn statements_and_examples_come_out_of_the_source() {
+        let source = r##"use aoc::input::normalize;
+
+/**
+ * --- Day 3: Gear Ratios ---
+ *
+ * The engine schematic (your puzzle input) consists of a visual
+ *  representation of the engine.
+
+
+467..114..
+...*......
+*/
+pub fn process(input: &str) -> String {
+    // "not an example"
+    input.len().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::process;
+
+    #[test]
+    fn it_works() {
+        let result = process(
+            "467..114..
+...*\\.....",
+        );
+        assert_eq!(result, "4361".to_string());
+        let result = process(include_str!("bin/example.txt"));
+        assert_eq!(result, "10");
+        let input = r#"a "quoted" line"#;
+        assert_eq!(result, "".to_string());
+    }
+
+    // #[test]
+    // fn later() {
+    //     assert_eq!(result, "1".to_string());
+    // }
+}
+"##;
+        let (title, text) = statement(source);
+        assert_eq!(title.as_deref(), Some("Gear Ratios"));
+        assert_eq!(
+            text,
+            "The engine schematic (your puzzle input) consists of a visual\n \
+             representation of the engine.\n\n467..114..\n...*......"
+        );
+
+        let examples = examples(source, |path| Some(format!("from {path}")));
+        assert_eq!(
+            examples,
+            [
+                Example {
+                    input: "467..114..\n...*\\.....".to_string(),
+                    answer: "4361".to_string(),
+                },
+                Example {
+                    input: "from bin/example.txt".to_string(),
+                    answer: "10".to_string(),
+                },
+            ]
+        );
+        assert_eq!(
+            literal("\"a\\\n   b\\u{e9}\" rest"),
+            Some(("ab\u{e9}".to_string(), " rest"))
+        );
+
+        assert_eq!(wrap("  one two three\n#.#", 9), "  one two\n  three\n#.#\n");
+    }
+}
@@ -0,0 +1,119 @@
+//! Shareable regression cases: a scrambled input and the answers the solvers
+//! gave for it when it was made.
+//!
+//! A fixture is a pair of files under `fixtures/dayN/`:
+//!
+//! ```text
+//! NAME.txt       the scrambled input
+//! NAME.answers   one `part<TAB>answer` line per part that was solved
+//! ```
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fixture {
+    pub day: u8,
+    pub name: String,
+    pub input: PathBuf,
+    pub answers: Vec<(u8, String)>,
+}
+
+impl Fixture {
+    /// Writes `input` and `answers` as fixture `name` of `day` under `dir`.
+    pub fn save(
+        dir: &Path,
+        day: u8,
+        name: &str,
+        input: &str,
+        answers: Vec<(u8, String)>,
+    ) -> Result<Fixture, String> {
+        let day_dir = dir.join(format!("day{day}"));
+        let error = |e: io::Error| format!("can't write to {}: {e}", day_dir.display());
+        fs::create_dir_all(&day_dir).map_err(error)?;
+        let text: String = answers
+            .iter()
+            .map(|(part, answer)| format!("{part}\t{answer}\n"))
+            .collect();
+        fs::write(day_dir.join(format!("{name}.answers")), text).map_err(error)?;
+        let path = day_dir.join(format!("{name}.txt"));
+        fs::write(&path, input).map_err(error)?;
+        Ok(Fixture {
+            day,
+            name: name.to_string(),
+            input: path,
+            answers,
+        })
+    }
+
+    /// Every fixture under `dir`, by day and then by name. No directory means
+    /// no fixtures.
+    pub fn load_all(dir: &Path) -> Result<Vec<Fixture>, String> {
+        let error = |path: &Path, e: io::Error| format!("can't read {}: {e}", path.display());
+        let days = match fs::read_dir(dir) {
+            Ok(days) => days,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(e) => return Err(error(dir, e)),
+        };
+        let mut fixtures = vec![];
+        for entry in days {
+            let day_dir = entry.map_err(|e| error(dir, e))?.path();
+            let Some(day) = day_dir
+                .file_name()
+                .and_then(|name| name.to_str()?.strip_prefix("day")?.parse().ok())
+            else {
+                continue;
+            };
+            for entry in fs::read_dir(&day_dir).map_err(|e| error(&day_dir, e))? {
+                let answers_path = entry.map_err(|e| error(&day_dir, e))?.path();
+                if answers_path.extension().is_none_or(|ext| ext != "answers") {
+                    continue;
+                }
+                let name = answers_path
+                    .file_stem()
+                    .map(|stem| stem.to_string_lossy().into_owned())
+                    .unwrap_or_default();
+                let text =
+                    fs::read_to_string(&answers_path).map_err(|e| error(&answers_path, e))?;
+                let answers = text
+                    .lines()
+                    .map(|line| {
+                        let (part, answer) = line.split_once('\t')?;
+                        Some((part.parse().ok()?, answer.to_string()))
+                    })
+                    .collect::<Option<Vec<_>>>()
+                    .ok_or_else(|| format!("{} is malformed", answers_path.display()))?;
+                fixtures.push(Fixture {
+                    day,
+                    input: day_dir.join(format!("{name}.txt")),
+                    name,
+                    answers,
+                });
+            }
+        }
+        fixtures.sort_by(|a, b| (a.day, &a.name).cmp(&(b.day, &b.name)));
+        Ok(fixtures)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixtures_round_trip() {
+        let dir = std::env::temp_dir().join(format!("runner-fixtures-{}", std::process::id()));
+        assert_eq!(Fixture::load_all(&dir).unwrap(), vec![]);
+        let answers = vec![(1, "6440".to_string()), (2, "5905".to_string())];
+        let saved = Fixture::save(&dir, 7, "seed-1", "32T3K 765\n", answers).unwrap();
+        Fixture::save(&dir, 25, "seed-2", "jqt: rhn\n", vec![]).unwrap();
+
+        let loaded = Fixture::load_all(&dir).unwrap();
+        assert_eq!(loaded.len(), 2);
+        assert_eq!(loaded[0], saved);
+        assert_eq!(fs::read_to_string(&loaded[0].input).unwrap(), "32T3K 765\n");
+        assert_eq!(loaded[1].day, 25);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,400 @@
+//! `runner fuzz`: feeds the solvers mangled copies of their examples, looking
+//! for input that makes them panic, and cuts each panicking input down to as
+//! little as still panics the same way.
+//!
+//! Panics are told apart by where they happened, so one bad slice found a
+//! thousand times is reported once. A solver that runs past the budget is
+//! reported too, but not shrunk: it may still be running, and enough of them
+//! would starve everything else, so after a few the solver is left out.
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io::Write;
+use std::panic;
+use std::path::Path;
+use std::sync::{Mutex, MutexGuard, Once};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc::rng::Rng;
+
+use crate::cache;
+use crate::mute::Muted;
+use crate::solvers::Solver;
+use crate::watchdog::{self, Budget, Outcome};
+
+/// Solves that ran past the budget before a solver is left out.
+const MAX_STUCK: usize = 3;
+/// Inputs kept per solver, examples included.
+const MAX_CORPUS: usize = 64;
+/// Solves spent shrinking one panic.
+const MAX_SHRINK_TRIES: usize = 2_000;
+
+const TOKENS: &[&str] = &[
+    "",
+    " ",
+    "  ",
+    "\n",
+    "\n\n",
+    "\t",
+    "0",
+    "1",
+    "-1",
+    "-",
+    "+",
+    "4294967296",
+    "99999999999999999999",
+    ",",
+    ":",
+    ";",
+    "=",
+    "@",
+    "#",
+    ".",
+    "(",
+    ")",
+    "|",
+    "é",
+];
+
+pub struct Settings {
+    /// How long to keep fuzzing for.
+    pub time: Duration,
+    /// Budget for each solve.
+    pub budget: Budget,
+    pub seed: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    pub day: u8,
+    pub part: u8,
+    /// Where the panic happened, or what went wrong for a solve that didn't panic.
+    pub what: String,
+    pub input: String,
+}
+
+/// While panics are being caught, the place of the latest one on each thread,
+/// as the panic hook saw it.
+static SITES: Mutex<Option<HashMap<String, String>>> = Mutex::new(None);
+
+fn sites() -> MutexGuard<'static, Option<HashMap<String, String>>> {
+    SITES.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Where `solver` panicked, or its message when the place wasn't caught.
+/// Messages often quote the input, so the place is the better signature.
+fn panic_site(solver: &Solver, message: &str) -> String {
+    let site = sites()
+        .as_mut()
+        .and_then(|sites| sites.remove(&watchdog::thread_name(solver)));
+    site.unwrap_or_else(|| message.to_string())
+}
+
+/// Stops catching panics when dropped, even if the catching panicked.
+struct Caught;
+
+impl Drop for Caught {
+    fn drop(&mut self) {
+        *sites() = None;
+    }
+}
+
+/// Runs `f` with panics recording where they happened instead of printing.
+///
+/// The hook is installed once and left in place, handing panics to the one
+/// before it outside of `f`: swapping hooks in and out would race with
+/// anything else that panics or sets a hook meanwhile, like other tests.
+fn catching_sites<R>(f: impl FnOnce() -> R) -> R {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let mut sites = sites();
+            let Some(sites) = sites.as_mut() else {
+                drop(sites);
+                return previous(info);
+            };
+            let thread = thread::current().name().unwrap_or_default().to_string();
+            let location = info
+                .location()
+                .map_or_else(String::new, |at| format!("{}:{}", at.file(), at.line()));
+            sites.insert(thread, location);
+        }));
+    });
+    *sites() = Some(HashMap::new());
+    let _caught = Caught;
+    f()
+}
+
+/// The site and message of the panic `input` causes, if it causes one.
+fn panics(solver: &Solver, input: &str, budget: Budget) -> Option<(String, String)> {
+    match watchdog::run(solver, input.to_string(), budget) {
+        Outcome::Panicked(message) => Some((panic_site(solver, &message), message)),
+        _ => None,
+    }
+}
+
+fn mutate(rng: &mut Rng, input: &str, corpus: &[String]) -> String {
+    let mut chars: Vec<char> = input.chars().collect();
+    for _ in 0..=rng.below(3) {
+        let at = |rng: &mut Rng, chars: &[char]| rng.below(chars.len() as u64 + 1) as usize;
+        match rng.below(8) {
+            0 => {
+                let start = at(rng, &chars);
+                let end = (start + 1 + rng.below(8) as usize).min(chars.len());
+                chars.drain(start..end);
+            }
+            1 => {
+                let token = TOKENS[rng.below(TOKENS.len() as u64) as usize];
+                let start = at(rng, &chars);
+                chars.splice(start..start, token.chars());
+            }
+            2 if !chars.is_empty() => {
+                let start = at(rng, &chars).min(chars.len() - 1);
+                chars[start] = char::from(b' ' + rng.below(95) as u8);
+            }
+            3 => chars.truncate(at(rng, &chars)),
+            4 => {
+                // Swaps a number for another, often an awkward one.
+                let start = at(rng, &chars);
+                if let Some(offset) = chars[start..].iter().position(char::is_ascii_digit) {
+                    let digits = chars[start + offset..]
+                        .iter()
+                        .take_while(|c| c.is_ascii_digit())
+                        .count();
+                    let number = match rng.below(4) {
+                        0 => "0".to_string(),
+                        1 => u64::MAX.to_string(),
+                        _ => rng.below(1000).to_string(),
+                    };
+                    let range = start + offset..start + offset + digits;
+                    chars.splice(range, number.chars());
+                }
+            }
+            _ => {
+                let mut lines: Vec<String> = chars
+                    .iter()
+                    .collect::<String>()
+                    .split('\n')
+                    .map(String::from)
+                    .collect();
+                let line = rng.below(lines.len() as u64) as usize;
+                match rng.below(4) {
+                    0 => drop(lines.remove(line)),
+                    1 => lines.insert(line, lines[line].clone()),
+                    2 => {
+                        let other = rng.below(lines.len() as u64) as usize;
+                        lines.swap(line, other);
+                    }
+                    _ => {
+                        // The start of this input with the end of another.
+                        let other = &corpus[rng.below(corpus.len() as u64) as usize];
+                        let tail: Vec<&str> = other.split('\n').collect();
+                        let from = rng.below(tail.len() as u64) as usize;
+                        lines.truncate(line);
+                        lines.extend(tail[from..].iter().map(|line| line.to_string()));
+                    }
+                }
+                chars = lines.join("\n").chars().collect();
+            }
+        }
+    }
+    chars.into_iter().collect()
+}
+
+/// Deletes lines, then characters, for as long as what's left still panics
+/// at `site`.
+fn shrink(solver: &Solver, input: &str, site: &str, budget: Budget) -> String {
+    let mut tries = 0;
+    let mut still_panics = |candidate: &str| {
+        tries += 1;
+        tries <= MAX_SHRINK_TRIES
+            && panics(solver, candidate, budget).is_some_and(|(other, _)| other == site)
+    };
+    let mut input = input.to_string();
+    let line_units = |text: &str| text.split_inclusive('\n').map(String::from).collect();
+    let char_units = |text: &str| text.chars().map(String::from).collect();
+    for split in [line_units as fn(&str) -> Vec<String>, char_units] {
+        let mut units = split(&input);
+        let mut chunk = units.len().div_ceil(2);
+        while chunk > 0 {
+            let mut start = 0;
+            while start < units.len() {
+                let end = (start + chunk).min(units.len());
+                let candidate: String = units[..start].concat() + &units[end..].concat();
+                if still_panics(&candidate) {
+                    units.drain(start..end);
+                } else {
+                    start = end;
+                }
+            }
+            chunk /= 2;
+        }
+        input = units.concat();
+    }
+    input
+}
+
+/// Fuzzes `solvers` until the time's up, writing progress to `out`. Panic
+/// sites are only told apart inside [`catching_sites`].
+fn fuzz(solvers: &[&'static Solver], settings: &Settings, out: &mut impl Write) -> Vec<Finding> {
+    let mut corpora: Vec<(&Solver, Vec<String>)> = solvers
+        .iter()
+        .filter_map(|&solver| {
+            let example = fs::read(crate::repo_root().join(solver.example?)).ok()?;
+            Some((solver, vec![aoc::input::decode(&example).ok()?]))
+        })
+        .collect();
+
+    let _ = writeln!(
+        out,
+        "fuzzing {} parts, --seed {}",
+        corpora.len(),
+        settings.seed
+    );
+    let mut rng = Rng::new(settings.seed);
+    let mut findings: Vec<Finding> = vec![];
+    let mut seen = HashSet::new();
+    let mut answers: HashMap<(u8, u8), HashSet<String>> = HashMap::new();
+    let mut stuck: HashMap<(u8, u8), usize> = HashMap::new();
+    let start = Instant::now();
+    let mut cases = 0;
+    let mut reported = Instant::now();
+    while start.elapsed() < settings.time && !corpora.is_empty() {
+        let pick = rng.below(corpora.len() as u64) as usize;
+        let (solver, corpus) = &mut corpora[pick];
+        let solver: &Solver = solver;
+        let key = (solver.day, solver.part);
+        let parent = &corpus[rng.below(corpus.len() as u64) as usize];
+        let input = mutate(&mut rng, parent, corpus);
+        cases += 1;
+
+        let found = match watchdog::run(solver, input.clone(), settings.budget) {
+            Outcome::Solved { answer, .. } => {
+                // A new answer is a hint that the input took a new path.
+                if answers.entry(key).or_default().insert(answer) && corpus.len() < MAX_CORPUS {
+                    corpus.push(input);
+                }
+                None
+            }
+            Outcome::Panicked(message) => {
+                let site = panic_site(solver, &message);
+                seen.insert((key, site.clone())).then(|| {
+                    let input = shrink(solver, &input, &site, settings.budget);
+                    // The message the smaller input panics with.
+                    let message = panics(solver, &input, settings.budget)
+                        .map_or(message, |(_, message)| message);
+                    (format!("panicked at {site}: {message}"), input)
+                })
+            }
+            stopped => {
+                let count = stuck.entry(key).or_default();
+                *count += 1;
+                if *count >= MAX_STUCK {
+                    corpora.remove(pick);
+                }
+                let what = stopped.to_string();
+                seen.insert((key, what.clone())).then_some((what, input))
+            }
+        };
+        if let Some((what, input)) = found {
+            let finding = Finding {
+                day: key.0,
+                part: key.1,
+                what,
+                input,
+            };
+            let _ = writeln!(
+                out,
+                "day {:>2} part {}: {} ({} bytes)",
+                finding.day,
+                finding.part,
+                finding.what,
+                finding.input.len()
+            );
+            findings.push(finding);
+        }
+        if reported.elapsed() > Duration::from_secs(5) {
+            let _ = writeln!(
+                out,
+                "{:.0?}: {cases} cases, {} findings",
+                start.elapsed(),
+                findings.len()
+            );
+            reported = Instant::now();
+        }
+    }
+    let _ = writeln!(out, "{cases} cases in {:.0?}", start.elapsed());
+    findings
+}
+
+/// Fuzzes with the process muted, saving each finding's input under `dir`.
+pub fn run(solvers: &[&'static Solver], settings: &Settings, dir: &Path) -> Result<bool, String> {
+    let (muted, mut tty) = Muted::start().map_err(|e| format!("can't mute the solvers: {e}"))?;
+    let findings = catching_sites(|| fuzz(solvers, settings, &mut tty));
+    drop(muted);
+
+    if !findings.is_empty() {
+        fs::create_dir_all(dir).map_err(|e| format!("can't write {}: {e}", dir.display()))?;
+    }
+    for finding in &findings {
+        let name = format!(
+            "day{}-part{}-{:016x}.txt",
+            finding.day,
+            finding.part,
+            cache::hash(&finding.what)
+        );
+        let path = dir.join(name);
+        fs::write(&path, &finding.input)
+            .map_err(|e| format!("can't write {}: {e}", path.display()))?;
+        println!(
+            "day {:>2} part {}: {}\n  {}",
+            finding.day,
+            finding.part,
+            finding.what,
+            path.display()
+        );
+    }
+    Ok(findings.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn panics_are_shrunk() {
+        let solver = Solver {
+            day: 99,
+            part: 1,
+            version: "1",
+            input: "",
+            example: None,
+            parse: None,
+            solve: |input, _| {
+                // Slices past the end of a short line with an `x` in it.
+                input
+                    .lines()
+                    .filter(|line| line.contains('x'))
+                    .map(|line| &line[..3])
+                    .collect()
+            },
+        };
+        let budget = Budget {
+            time: Duration::from_secs(5),
+            memory: 64 << 20,
+        };
+        assert_eq!(panics(&solver, "abcdef\nxyzzy\nab\n", budget), None);
+        let input = "abcdef\nxyzzy\nab\nax\nuvw\n";
+        let (site, _) = catching_sites(|| panics(&solver, input, budget)).unwrap();
+        assert!(site.starts_with("src/fuzz.rs:"), "{site}");
+        let shrunk = catching_sites(|| shrink(&solver, input, &site, budget));
+        assert_eq!(shrunk, "x");
+
+        let mut rng = Rng::new(3);
+        let corpus = vec![input.to_string()];
+        let mutants: HashSet<String> = (0..50).map(|_| mutate(&mut rng, input, &corpus)).collect();
+        assert!(mutants.len() > 40);
+    }
+}
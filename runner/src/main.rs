@@ -0,0 +1,602 @@
+//! Runs the day solvers against their real input.
+//!
+//! ```text
+//! cargo run --release -- run 23:2 --timeout 60
+//! ```
+
+mod bench;
+mod cache;
+mod catalogue;
+mod fixture;
+mod fuzz;
+mod meter;
+mod mute;
+mod scramble;
+mod serve;
+mod solvers;
+mod tui;
+mod watchdog;
+
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use aoc::rng::Rng;
+use bench::History;
+use cache::Cache;
+use fixture::Fixture;
+use solvers::Solver;
+use watchdog::{Budget, Outcome};
+
+const USAGE: &str = "\
+usage: runner <command> [options]
+
+commands:
+  list                  every day and part, with its title and where its input lives
+  describe DAY [PART]   the puzzle statement and examples, for both parts by default
+  run [DAY[:PART]]...   solve with the real input, everything by default
+  cache                 the cached answers, marking ones from old solver versions
+  cache prune [--all]   drop cached answers from old solver versions, or all of them
+  serve                 answer HTTP requests: GET /days, POST /days/DAY/PART
+  tui                   browse the days, run parts and watch them animate
+  bench [DAY[:PART]]... time the parts and add them to the benchmark history
+  bench compare [DAY[:PART]]...
+                        compare the latest benchmarks with a baseline, failing
+                        when a part got slower or allocates more
+  fixture DAY[:PART]... scramble the real input into a shareable fixture under
+                        fixtures/dayN/, recording the answers for it (days
+                        7, 8, 24 and 25)
+  fixture check [DAY[:PART]]...
+                        solve the fixtures again and compare the answers
+  fuzz [DAY[:PART]]...  feed the solvers mangled examples, saving the inputs
+                        that make them panic under target/fuzz/
+
+run options:
+  --input FILE          read FILE instead of the real input
+  --no-cache            solve again even when an answer is cached
+  --timeout SECONDS     wall-clock budget for each part (default 30)
+  --memory MIB          memory budget for each part (default 2048)
+
+fixture options, along with --input, --timeout and --memory:
+  --seed N              seed for the scrambling, and the fixture's name
+                        (default: the time)
+
+bench options, along with --input, --timeout and --memory:
+  --runs N              solves per part, the median is kept (default 5)
+  --history FILE        where benchmarks are kept (default bench-history.tsv)
+  --baseline COMMIT     compare with the latest run on COMMIT, or a run id,
+                        instead of the run before the latest
+  --threshold PERCENT   how much worse a part may get (default 10)
+
+fuzz options, along with --memory and --seed:
+  --time SECONDS        how long to fuzz for (default 60)
+  --case-timeout SECONDS
+                        wall-clock budget for each solve (default 1)
+
+serve options, along with --timeout and --memory:
+  --addr ADDR           where to listen (default 127.0.0.1:8025)
+  --max-body BYTES      largest input accepted (default 1048576)
+  --max-connections N   most requests answered at once (default 64)";
+
+struct Options {
+    targets: Vec<String>,
+    input: Option<PathBuf>,
+    budget: Budget,
+    use_cache: bool,
+    addr: String,
+    max_body: usize,
+    max_connections: usize,
+    runs: usize,
+    history: PathBuf,
+    baseline: Option<String>,
+    threshold: f64,
+    seed: Option<u64>,
+    time: Duration,
+    case_timeout: Duration,
+}
+
+impl Options {
+    fn parse(args: &[String]) -> Result<Options, String> {
+        let mut options = Options {
+            targets: vec![],
+            input: None,
+            budget: Budget {
+                time: Duration::from_secs(30),
+                memory: 2048 << 20,
+            },
+            use_cache: true,
+            addr: "127.0.0.1:8025".to_string(),
+            max_body: 1 << 20,
+            max_connections: 64,
+            runs: 5,
+            history: repo_root().join("bench-history.tsv"),
+            baseline: None,
+            threshold: 10.0,
+            seed: None,
+            time: Duration::from_secs(60),
+            case_timeout: Duration::from_secs(1),
+        };
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let mut value = || args.next().ok_or_else(|| format!("`{arg}` needs a value"));
+            match arg.as_str() {
+                "--input" => options.input = Some(PathBuf::from(value()?)),
+                "--no-cache" => options.use_cache = false,
+                "--addr" => options.addr = value()?.clone(),
+                "--max-body" => {
+                    options.max_body = value()?
+                        .parse()
+                        .map_err(|_| "`--max-body` should be a number of bytes".to_string())?;
+                }
+                "--max-connections" => {
+                    options.max_connections = value()?
+                        .parse()
+                        .ok()
+                        .filter(|&max| max > 0)
+                        .ok_or_else(|| {
+                            "`--max-connections` should be a positive number".to_string()
+                        })?;
+                }
+                "--runs" => {
+                    options.runs = value()?
+                        .parse()
+                        .ok()
+                        .filter(|&runs| runs > 0)
+                        .ok_or_else(|| "`--runs` should be a positive number".to_string())?;
+                }
+                "--history" => options.history = PathBuf::from(value()?),
+                "--baseline" => options.baseline = Some(value()?.clone()),
+                "--threshold" => {
+                    options.threshold = value()?
+                        .parse()
+                        .map_err(|_| "`--threshold` should be a percentage".to_string())?;
+                }
+                "--seed" => {
+                    let seed = value()?
+                        .parse()
+                        .map_err(|_| "`--seed` should be a whole number".to_string())?;
+                    options.seed = Some(seed);
+                }
+                "--timeout" => options.budget.time = seconds(arg, value()?)?,
+                "--time" => options.time = seconds(arg, value()?)?,
+                "--case-timeout" => options.case_timeout = seconds(arg, value()?)?,
+                "--memory" => {
+                    let mib: usize = value()?
+                        .parse()
+                        .map_err(|_| "`--memory` should be a whole number of MiB".to_string())?;
+                    options.budget.memory = mib << 20;
+                }
+                flag if flag.starts_with("--") => return Err(format!("unknown option `{flag}`")),
+                target => options.targets.push(target.to_string()),
+            }
+        }
+        Ok(options)
+    }
+}
+
+fn seconds(flag: &str, value: &str) -> Result<Duration, String> {
+    value
+        .parse()
+        .ok()
+        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+        .ok_or_else(|| format!("`{flag}` should be a number of seconds"))
+}
+
+/// The directory holding the `dayN` crates.
+fn repo_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the runner lives inside the repository")
+}
+
+fn cache_path() -> PathBuf {
+    repo_root().join("target").join("answers.tsv")
+}
+
+fn fixtures_path() -> PathBuf {
+    repo_root().join("fixtures")
+}
+
+fn read_input(path: &Path) -> Result<String, String> {
+    let bytes = std::fs::read(path).map_err(|e| format!("can't read {}: {e}", path.display()))?;
+    aoc::input::decode(&bytes).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+fn list() {
+    let puzzles = catalogue::load(repo_root());
+    for solver in solvers::SOLVERS {
+        let title = puzzles
+            .iter()
+            .find(|puzzle| puzzle.day == solver.day)
+            .and_then(|puzzle| puzzle.title.as_deref())
+            .unwrap_or("");
+        println!(
+            "day {:>2} part {}  {title:<32} {}",
+            solver.day, solver.part, solver.input
+        );
+    }
+}
+
+fn describe(args: &[String]) -> Result<bool, String> {
+    let (day, part) = match args {
+        [day] => (day, None),
+        [day, part] => (day, Some(part)),
+        _ => return Err(USAGE.to_string()),
+    };
+    let day: u8 = day.parse().map_err(|_| format!("`{day}` isn't a day"))?;
+    let part = part
+        .map(|part| match part.parse() {
+            Ok(part @ (1 | 2)) => Ok(part),
+            _ => Err(format!("`{part}` isn't a part")),
+        })
+        .transpose()?;
+    let puzzle = catalogue::load_day(repo_root(), day).ok_or(format!("there's no day {day}"))?;
+
+    let statements = match part {
+        Some(part) => vec![puzzle
+            .part(part)
+            .ok_or(format!("there's no part {part} of day {day}"))?],
+        None => puzzle.parts.iter().collect(),
+    };
+
+    println!("{}", puzzle.heading());
+    for statement in statements {
+        println!("\n--- Part {} ---\n", statement.part);
+        if statement.text.is_empty() {
+            println!("(the puzzle text hasn't been copied in yet)");
+        } else {
+            print!("{}", catalogue::wrap(&statement.text, 80));
+        }
+        for example in &statement.examples {
+            println!("\nFor this example the answer is {}:\n", example.answer);
+            for line in example.input.trim_end().lines() {
+                println!("    {line}");
+            }
+        }
+    }
+    Ok(true)
+}
+
+fn run(options: &Options) -> Result<bool, String> {
+    let selected = solvers::select(&options.targets)?;
+    let mut cache = Cache::open(&cache_path())?;
+    let mut all_solved = true;
+    for solver in selected {
+        let path = match &options.input {
+            Some(path) => path.clone(),
+            None => repo_root().join(solver.input),
+        };
+        let input = match read_input(&path) {
+            Ok(input) => input,
+            Err(e) => {
+                println!("day {:>2} part {}: {e}", solver.day, solver.part);
+                all_solved = false;
+                continue;
+            }
+        };
+        let key = cache::Key::new(solver, &input);
+        if let Some(entry) = cache.get(&key).filter(|_| options.use_cache) {
+            println!(
+                "day {:>2} part {}: {} (cached, took {:.2?})",
+                solver.day, solver.part, entry.answer, entry.elapsed
+            );
+            continue;
+        }
+        let outcome = watchdog::run(solver, input, options.budget);
+        if let Outcome::Solved { answer, elapsed } = &outcome {
+            cache.insert(key, answer.clone(), *elapsed);
+            // Saved as we go so the slow parts stick even if a later one is interrupted.
+            cache.save()?;
+        }
+        all_solved &= matches!(outcome, Outcome::Solved { .. });
+        println!("day {:>2} part {}: {outcome}", solver.day, solver.part);
+    }
+    Ok(all_solved)
+}
+
+/// Whether some solver still produces answers like this one.
+fn is_current(entry: &cache::Entry) -> bool {
+    solvers::SOLVERS.iter().any(|solver| {
+        solver.day == entry.key.day
+            && solver.part == entry.key.part
+            && solver.version == entry.key.version
+    })
+}
+
+fn cache(args: &[String]) -> Result<bool, String> {
+    let mut cache = Cache::open(&cache_path())?;
+    match args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        [] => {
+            println!("{}", cache_path().display());
+            for entry in cache.entries() {
+                println!(
+                    "day {:>2} part {}  v{:<3} {:016x}  {} ({:.2?}){}",
+                    entry.key.day,
+                    entry.key.part,
+                    entry.key.version,
+                    entry.key.input,
+                    entry.answer,
+                    entry.elapsed,
+                    if is_current(entry) { "" } else { "  stale" }
+                );
+            }
+        }
+        ["prune"] => {
+            let dropped = cache.retain(is_current);
+            cache.save()?;
+            println!("dropped {dropped} stale answers");
+        }
+        ["prune", "--all"] => {
+            let dropped = cache.retain(|_| false);
+            cache.save()?;
+            println!("dropped {dropped} answers");
+        }
+        _ => return Err(USAGE.to_string()),
+    }
+    Ok(true)
+}
+
+fn fixture(options: &Options) -> Result<bool, String> {
+    if options.targets.is_empty() {
+        return Err(USAGE.to_string());
+    }
+    let selected = solvers::select(&options.targets)?;
+    let seed = options.seed.unwrap_or_else(|| {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |since| since.as_nanos() as u64)
+    });
+    let mut days: Vec<u8> = selected.iter().map(|solver| solver.day).collect();
+    days.dedup();
+    let mut all_solved = true;
+    for day in days {
+        if !scramble::DAYS.contains(&day) {
+            return Err(format!("there's no scrambler for day {day}"));
+        }
+        let parts: Vec<&Solver> = selected
+            .iter()
+            .copied()
+            .filter(|solver| solver.day == day)
+            .collect();
+        let path = match &options.input {
+            Some(path) => path.clone(),
+            None => repo_root().join(parts[0].input),
+        };
+        let input = read_input(&path)?;
+        let scrambled = scramble::scramble(day, &input, &mut Rng::new(seed))
+            .expect("day has a scrambler")
+            .map_err(|e| format!("{}: {e}", path.display()))?;
+
+        let mut answers = vec![];
+        for solver in parts {
+            match watchdog::run(solver, scrambled.clone(), options.budget) {
+                Outcome::Solved { answer, .. } => answers.push((solver.part, answer)),
+                failed => {
+                    println!(
+                        "day {:>2} part {}: {failed}, not recorded",
+                        day, solver.part
+                    );
+                    all_solved = false;
+                }
+            }
+        }
+        let fixture = Fixture::save(
+            &fixtures_path(),
+            day,
+            &format!("seed-{seed}"),
+            &scrambled,
+            answers,
+        )?;
+        println!("{}", fixture.input.display());
+        for (part, answer) in &fixture.answers {
+            println!("  part {part}: {answer}");
+        }
+    }
+    Ok(all_solved)
+}
+
+fn fixture_check(options: &Options) -> Result<bool, String> {
+    let selected = solvers::select(&options.targets)?;
+    let mut all_match = true;
+    for fixture in Fixture::load_all(&fixtures_path())? {
+        let input = read_input(&fixture.input)?;
+        for (part, expected) in &fixture.answers {
+            let Some(solver) = selected
+                .iter()
+                .find(|solver| solver.day == fixture.day && solver.part == *part)
+            else {
+                continue;
+            };
+            let label = format!("day {:>2} part {} {}", fixture.day, part, fixture.name);
+            match watchdog::run(solver, input.clone(), options.budget) {
+                Outcome::Solved { answer, elapsed } if answer == *expected => {
+                    println!("{label}: ok ({elapsed:.2?})");
+                }
+                Outcome::Solved { answer, .. } => {
+                    println!("{label}: got {answer}, expected {expected}");
+                    all_match = false;
+                }
+                failed => {
+                    println!("{label}: {failed}");
+                    all_match = false;
+                }
+            }
+        }
+    }
+    Ok(all_match)
+}
+
+fn bench(options: &Options) -> Result<bool, String> {
+    let selected = solvers::select(&options.targets)?;
+    let mut history = History::open(&options.history)?;
+    let run = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_millis() as u64);
+    let commit = bench::head_commit(repo_root()).unwrap_or_else(|| "-".to_string());
+    let mut all_solved = true;
+    'solvers: for solver in selected {
+        let path = match &options.input {
+            Some(path) => path.clone(),
+            None => repo_root().join(solver.input),
+        };
+        let input = match read_input(&path) {
+            Ok(input) => input,
+            Err(e) => {
+                println!("day {:>2} part {}: {e}", solver.day, solver.part);
+                all_solved = false;
+                continue;
+            }
+        };
+        let mut times = vec![];
+        let mut allocations = vec![];
+        for _ in 0..options.runs {
+            let meter = meter::Meter::new();
+            match watchdog::run_metered(solver, input.clone(), options.budget, meter) {
+                Outcome::Solved { elapsed, .. } => {
+                    times.push(elapsed);
+                    allocations.push(meter.allocations());
+                }
+                failed => {
+                    println!("day {:>2} part {}: {failed}", solver.day, solver.part);
+                    all_solved = false;
+                    continue 'solvers;
+                }
+            }
+        }
+        let record = bench::Record {
+            run,
+            commit: commit.clone(),
+            day: solver.day,
+            part: solver.part,
+            runs: options.runs,
+            median: bench::median(&mut times),
+            allocations: bench::median(&mut allocations),
+        };
+        println!(
+            "day {:>2} part {}: {:.2?} median of {}, {} allocations",
+            solver.day, solver.part, record.median, record.runs, record.allocations
+        );
+        history.append(record)?;
+    }
+    Ok(all_solved)
+}
+
+fn bench_compare(options: &Options) -> Result<bool, String> {
+    let selected = solvers::select(&options.targets)?;
+    let history = History::open(&options.history)?;
+    let (latest, baseline) = history.latest_and_baseline(options.baseline.as_deref())?;
+    let short = |commit: &str| commit.chars().take(10).collect::<String>();
+    println!(
+        "{} against {}, allowing {}%",
+        short(&latest[0].commit),
+        short(&baseline[0].commit),
+        options.threshold
+    );
+    let mut regressed = 0;
+    for after in latest {
+        if !selected
+            .iter()
+            .any(|solver| solver.day == after.day && solver.part == after.part)
+        {
+            continue;
+        }
+        let label = format!("day {:>2} part {}", after.day, after.part);
+        let Some(before) = baseline
+            .iter()
+            .find(|before| before.day == after.day && before.part == after.part)
+        else {
+            println!("{label}: {:.2?}, not in the baseline", after.median);
+            continue;
+        };
+        let time = bench::change(before.median.as_secs_f64(), after.median.as_secs_f64());
+        let allocations = bench::change(before.allocations as f64, after.allocations as f64);
+        let worse = time > options.threshold || allocations > options.threshold;
+        regressed += usize::from(worse);
+        println!(
+            "{label}: {:.2?} -> {:.2?} ({time:+.1}%), {} -> {} allocations ({allocations:+.1}%){}",
+            before.median,
+            after.median,
+            before.allocations,
+            after.allocations,
+            if worse { "  REGRESSED" } else { "" }
+        );
+    }
+    if regressed > 0 {
+        println!("{regressed} parts regressed");
+    }
+    Ok(regressed == 0)
+}
+
+fn serve(options: &Options) -> Result<bool, String> {
+    if !options.targets.is_empty() {
+        return Err(USAGE.to_string());
+    }
+    let limits = serve::Limits {
+        budget: options.budget,
+        max_body: options.max_body,
+        max_connections: options.max_connections,
+    };
+    let server = serve::Server::bind(&options.addr, limits)
+        .map_err(|e| format!("can't listen on {}: {e}", options.addr))?;
+    if let Ok(addr) = server.local_addr() {
+        println!("listening on http://{addr}");
+    }
+    server.serve().map_err(|e| e.to_string())?;
+    Ok(true)
+}
+
+fn fuzz(options: &Options) -> Result<bool, String> {
+    let settings = fuzz::Settings {
+        time: options.time,
+        budget: Budget {
+            time: options.case_timeout,
+            memory: options.budget.memory,
+        },
+        seed: options.seed.unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |since| since.as_nanos() as u64)
+        }),
+    };
+    let solvers = solvers::select(&options.targets)?;
+    fuzz::run(
+        &solvers,
+        &settings,
+        &repo_root().join("target").join("fuzz"),
+    )
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("list") => {
+            list();
+            Ok(true)
+        }
+        Some("describe") => describe(&args[1..]),
+        Some("run") => Options::parse(&args[1..]).and_then(|options| run(&options)),
+        Some("cache") => cache(&args[1..]),
+        Some("tui") => Options::parse(&args[1..]).and_then(|options| tui::run(options.budget)),
+        Some("fixture") => match args.get(1).map(String::as_str) {
+            Some("check") => Options::parse(&args[2..]).and_then(|options| fixture_check(&options)),
+            _ => Options::parse(&args[1..]).and_then(|options| fixture(&options)),
+        },
+        Some("bench") => match args.get(1).map(String::as_str) {
+            Some("compare") => {
+                Options::parse(&args[2..]).and_then(|options| bench_compare(&options))
+            }
+            _ => Options::parse(&args[1..]).and_then(|options| bench(&options)),
+        },
+        Some("fuzz") => Options::parse(&args[1..]).and_then(|options| fuzz(&options)),
+        Some("serve") => Options::parse(&args[1..]).and_then(|options| serve(&options)),
+        _ => Err(USAGE.to_string()),
+    };
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::from(2)
+        }
+    }
+}
@@ -0,0 +1,118 @@
+//! A global allocator that keeps a running total of live bytes per solver.
+//!
+//! Each solver runs on its own thread, and that thread attaches a [`Meter`]
+//! before calling in. Every allocation and free made on the thread is added to
+//! the meter, so the watchdog can see how much the solver is holding without
+//! counting the runner's own memory or another solver's. The meter also counts
+//! the allocations themselves, for `runner bench`.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicIsize, AtomicUsize, Ordering};
+
+pub struct Counting;
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+thread_local! {
+    static METER: Cell<Option<&'static Counters>> = const { Cell::new(None) };
+}
+
+struct Counters {
+    live: AtomicIsize,
+    allocations: AtomicUsize,
+}
+
+/// Adds `delta` live bytes, from `allocations` new allocations.
+fn record(delta: isize, allocations: usize) {
+    // `try_with` because the allocator still runs while thread locals are torn down.
+    let _ = METER.try_with(|meter| {
+        if let Some(meter) = meter.get() {
+            meter.live.fetch_add(delta, Ordering::Relaxed);
+            meter.allocations.fetch_add(allocations, Ordering::Relaxed);
+        }
+    });
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record(layout.size() as isize, 1);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record(layout.size() as isize, 1);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record(-(layout.size() as isize), 0);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            // Growing a `Vec` goes through here, so it counts as allocating.
+            record(new_size as isize - layout.size() as isize, 1);
+        }
+        new
+    }
+}
+
+/// Live bytes allocated by the threads attached to it.
+#[derive(Clone, Copy)]
+pub struct Meter(&'static Counters);
+
+impl Meter {
+    pub fn new() -> Meter {
+        // Leaked so the allocator can hold on to it without reference counting.
+        // That's two words per solver run.
+        Meter(Box::leak(Box::new(Counters {
+            live: AtomicIsize::new(0),
+            allocations: AtomicUsize::new(0),
+        })))
+    }
+
+    /// Counts everything the current thread allocates from now on.
+    pub fn attach(self) {
+        METER.with(|meter| meter.set(Some(self.0)));
+    }
+
+    pub fn bytes(self) -> usize {
+        self.0.live.load(Ordering::Relaxed).max(0) as usize
+    }
+
+    /// How many times the attached threads have allocated or reallocated.
+    pub fn allocations(self) -> usize {
+        self.0.allocations.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_only_the_attached_thread() {
+        let meter = Meter::new();
+        let held = std::thread::spawn(move || {
+            meter.attach();
+            drop(vec![0u8; 1 << 20]);
+            vec![0u8; 1 << 20]
+        })
+        .join()
+        .unwrap();
+        let _elsewhere = vec![0u8; 1 << 20];
+        // Thread teardown frees a few bytes that were allocated before attaching.
+        assert!(meter.bytes().abs_diff(held.len()) < 4096);
+        assert!((2..10).contains(&meter.allocations()));
+    }
+}
@@ -0,0 +1,50 @@
+//! Silences the solvers' `println!`s and `dbg!`s while the runner wants the
+//! terminal to itself.
+
+use std::fs::File;
+use std::io::{self, Write};
+use std::os::fd::{AsRawFd, FromRawFd, RawFd};
+
+/// Stdout and stderr point at /dev/null until this is dropped.
+pub struct Muted {
+    stdout: RawFd,
+    stderr: RawFd,
+}
+
+fn dup(fd: RawFd) -> io::Result<RawFd> {
+    // SAFETY: `dup` has no memory-safety requirements; a bad descriptor is an error.
+    match unsafe { libc::dup(fd) } {
+        -1 => Err(io::Error::last_os_error()),
+        copy => Ok(copy),
+    }
+}
+
+impl Muted {
+    /// Mutes the process, returning a file that still writes to the real stdout.
+    pub fn start() -> io::Result<(Muted, File)> {
+        let null = File::options().write(true).open("/dev/null")?;
+        let (stdout, stderr) = (dup(1)?, dup(2)?);
+        // SAFETY: the copy is fresh and owned by nothing else.
+        let tty = unsafe { File::from_raw_fd(dup(1)?) };
+        io::stdout().flush()?;
+        // SAFETY: as for `dup`.
+        unsafe {
+            libc::dup2(null.as_raw_fd(), 1);
+            libc::dup2(null.as_raw_fd(), 2);
+        }
+        Ok((Muted { stdout, stderr }, tty))
+    }
+}
+
+impl Drop for Muted {
+    fn drop(&mut self) {
+        let _ = io::stdout().flush();
+        // SAFETY: as for `dup`; these are the copies saved in `start`.
+        unsafe {
+            libc::dup2(self.stdout, 1);
+            libc::dup2(self.stderr, 2);
+            libc::close(self.stdout);
+            libc::close(self.stderr);
+        }
+    }
+}
@@ -0,0 +1,293 @@
+//! Scrambles a real puzzle input into one that can be shared.
+//!
+//! The scrambled input has the same shape as the original, so a solver works
+//! as hard on it and goes down the same kinds of paths. Names, labels,
+//! positions and numbers are changed, though, so its answers are different
+//! and nothing of the original input can be read back out of it, with one
+//! exception: day 8's instructions are kept as they are, since the network's
+//! loops are built around them.
+
+use std::collections::{HashMap, HashSet};
+
+use aoc::parse::{self, Scanner};
+use aoc::rng::Rng;
+
+/// The days that have a scrambler.
+pub const DAYS: [u8; 4] = [7, 8, 24, 25];
+
+/// `None` when there's no scrambler for `day`.
+pub fn scramble(day: u8, input: &str, rng: &mut Rng) -> Option<parse::Result<String>> {
+    let input = aoc::input::normalize(input);
+    Some(match day {
+        7 => hands(&input, rng),
+        8 => network(&input, rng),
+        24 => hailstones(&input, rng),
+        25 => wiring(&input, rng),
+        _ => return None,
+    })
+}
+
+fn letter(rng: &mut Rng, letters: &[u8]) -> char {
+    char::from(letters[rng.below(letters.len() as u64) as usize])
+}
+
+/// New names for old ones, never giving two old names the same new one.
+struct Names<'a> {
+    names: HashMap<&'a str, String>,
+    taken: HashSet<String>,
+}
+
+impl<'a> Names<'a> {
+    /// `keep` are names that stay as they are.
+    fn new(keep: &[&'a str]) -> Names<'a> {
+        Names {
+            names: keep.iter().map(|&name| (name, name.to_string())).collect(),
+            taken: keep.iter().map(|name| name.to_string()).collect(),
+        }
+    }
+
+    fn rename(
+        &mut self,
+        name: &'a str,
+        rng: &mut Rng,
+        fresh: impl Fn(&mut Rng) -> String,
+    ) -> String {
+        if let Some(renamed) = self.names.get(name) {
+            return renamed.clone();
+        }
+        let renamed = loop {
+            let candidate = fresh(rng);
+            if self.taken.insert(candidate.clone()) {
+                break candidate;
+            }
+        };
+        self.names.insert(name, renamed.clone());
+        renamed
+    }
+}
+
+fn lines(mut lines: Vec<String>) -> String {
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+/// Day 7. The card labels are swapped around and every hand is shuffled, so
+/// each hand keeps its type. Jokers stay jokers so part 2 keeps its types too.
+/// Bids are replaced by random ones with as many digits.
+fn hands(input: &str, rng: &mut Rng) -> parse::Result<String> {
+    let mut labels: Vec<char> = "23456789TQKA".chars().collect();
+    rng.shuffle(&mut labels);
+    let relabel: HashMap<char, char> = "23456789TQKA".chars().zip(labels).collect();
+
+    let mut hands = HashSet::new();
+    let mut seen = HashSet::new();
+    let mut out = vec![];
+    for mut line in Scanner::new(input).lines() {
+        let hand = line.word()?;
+        // The reshuffling below runs out of new arrangements otherwise.
+        if !hands.insert(hand) {
+            return Err(line.error_at(hand, format!("`{hand}` is already a hand")));
+        }
+        let bid: u32 = line.int()?;
+        line.finish()?;
+        let mut cards = vec![];
+        for card in hand.chars() {
+            match relabel.get(&card) {
+                Some(&card) => cards.push(card),
+                None if card == 'J' => cards.push('J'),
+                None => return Err(line.error_at(hand, format!("`{card}` is not a card"))),
+            }
+        }
+        // Relabelling can't make two hands the same, but shuffling can. Each
+        // hand shuffled into these cards came from a different arrangement of
+        // them, as no hand repeats, so there's always one left that hasn't
+        // been used.
+        let shuffled = loop {
+            rng.shuffle(&mut cards);
+            let shuffled: String = cards.iter().collect();
+            if seen.insert(shuffled.clone()) {
+                break shuffled;
+            }
+        };
+        let digits = bid.to_string().len() as u32;
+        let low = if digits == 1 {
+            0
+        } else {
+            10_i64.pow(digits - 1)
+        };
+        let high = (10_i64.pow(digits) - 1).min(u32::MAX.into());
+        out.push(format!("{shuffled} {}", rng.between(low, high)));
+    }
+    rng.shuffle(&mut out);
+    Ok(lines(out))
+}
+
+/// Day 8. Nodes get new names ending in the same kind of letter, since part 2
+/// starts at every `..A` and stops at every `..Z`. `AAA` and `ZZZ` keep theirs.
+/// The instructions are kept: part 2's loops only line up with them.
+fn network(input: &str, rng: &mut Rng) -> parse::Result<String> {
+    let mut blocks = Scanner::new(input).blocks();
+    let route = blocks
+        .next()
+        .ok_or_else(|| Scanner::new(input).error("expected the instructions"))?;
+    let network = blocks
+        .next()
+        .ok_or_else(|| route.error("expected the network after the instructions"))?;
+
+    let records = network
+        .lines()
+        .map(|line| line.record())
+        .collect::<parse::Result<Vec<_>>>()?;
+    let mut names = Names::new(&["AAA", "ZZZ"]);
+    // Keeps the last letter when it's an `A` or a `Z`, and keeps it from
+    // becoming one otherwise.
+    let node = |name: &str| {
+        let last = name.chars().last();
+        move |rng: &mut Rng| {
+            let any = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+            let last = match last {
+                Some('A') => 'A',
+                Some('Z') => 'Z',
+                _ => letter(rng, b"BCDEFGHIJKLMNOPQRSTUVWXY"),
+            };
+            format!("{}{}{last}", letter(rng, any), letter(rng, any))
+        }
+    };
+    let mut out: Vec<String> = records
+        .iter()
+        .map(|record| {
+            format!(
+                "{} = ({}, {})",
+                names.rename(record.name, rng, node(record.name)),
+                names.rename(record.left, rng, node(record.left)),
+                names.rename(record.right, rng, node(record.right))
+            )
+        })
+        .collect();
+    rng.shuffle(&mut out);
+    Ok(format!("{}\n\n{}", route.rest().trim_end(), lines(out)))
+}
+
+/// Day 24. Every hailstone is moved by the same offset, so they all still
+/// meet the rock: its starting position moves by that offset too.
+fn hailstones(input: &str, rng: &mut Rng) -> parse::Result<String> {
+    let offset = [(); 3].map(|_| rng.between(-1_000_000_000, 1_000_000_000));
+    let mut out = vec![];
+    for line in Scanner::new(input).lines() {
+        let (position, velocity) = line.split_once("@")?;
+        let numbers = |part: Scanner| -> parse::Result<Vec<i64>> {
+            let numbers =
+                parse::ints(&part.rest().replace(',', " ")).map_err(|e| part.error(e.message))?;
+            if numbers.len() != 3 {
+                return Err(part.error("expected three numbers"));
+            }
+            Ok(numbers)
+        };
+        let position = numbers(position)?;
+        let velocity = numbers(velocity)?;
+        let moved: Vec<String> = position
+            .iter()
+            .zip(offset)
+            .map(|(p, offset)| (p + offset).to_string())
+            .collect();
+        let velocity: Vec<String> = velocity.iter().map(i64::to_string).collect();
+        out.push(format!("{} @ {}", moved.join(", "), velocity.join(", ")));
+    }
+    rng.shuffle(&mut out);
+    Ok(lines(out))
+}
+
+/// Day 25. Components get new names, and the lines and the connections on
+/// each line are shuffled.
+fn wiring(input: &str, rng: &mut Rng) -> parse::Result<String> {
+    let mut names = Names::new(&[]);
+    let lowercase = |length: usize| {
+        move |rng: &mut Rng| {
+            (0..length)
+                .map(|_| letter(rng, b"abcdefghijklmnopqrstuvwxyz"))
+                .collect::<String>()
+        }
+    };
+    let mut out = vec![];
+    for line in Scanner::new(input).lines() {
+        let (mut component, connected) = line.split_once(":")?;
+        let name = component.word()?;
+        component.finish()?;
+        let mut connected: Vec<String> = connected
+            .rest()
+            .split_whitespace()
+            .map(|other| names.rename(other, rng, lowercase(other.len())))
+            .collect();
+        if connected.is_empty() {
+            return Err(line.error("expected connected components"));
+        }
+        rng.shuffle(&mut connected);
+        let name = names.rename(name, rng, lowercase(name.len()));
+        out.push(format!("{name}: {}", connected.join(" ")));
+    }
+    rng.shuffle(&mut out);
+    Ok(lines(out))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sorted_words(text: &str) -> Vec<usize> {
+        let mut lengths: Vec<usize> = text.split_whitespace().map(str::len).collect();
+        lengths.sort();
+        lengths
+    }
+
+    #[test]
+    fn scrambles_keep_the_shape() {
+        let mut rng = Rng::new(1);
+        let hands = scramble(7, "32T3K 765\nT55J5 684\nKK677 28\n", &mut rng)
+            .unwrap()
+            .unwrap();
+        assert_eq!(sorted_words(&hands), [2, 3, 3, 5, 5, 5]);
+        assert!(hands.lines().any(|line| line.contains('J')));
+        // Every arrangement of these cards but one is already taken, so the
+        // last hand can only get that one.
+        let taken = "AKKKK 1\nKAKKK 2\nKKAKK 3\nKKKAK 4\nKKKKA 5\n";
+        let hands = scramble(7, taken, &mut rng).unwrap().unwrap();
+        let mut hands: Vec<&str> = hands.lines().map(|line| &line[..5]).collect();
+        hands.sort();
+        hands.dedup();
+        assert_eq!(hands.len(), 5);
+
+        let network = "RL\n\nAAA = (BBB, CCC)\nBBB = (DDA, EEZ)\nCCC = (ZZZ, GGG)\n";
+        let scrambled = scramble(8, network, &mut rng).unwrap().unwrap();
+        assert!(scrambled.starts_with("RL\n\n"));
+        assert!(scrambled.contains("AAA = ("));
+        assert!(scrambled.contains("ZZZ"));
+        assert_eq!(scrambled.matches("A,").count(), 1);
+        assert_eq!(scrambled.matches("Z)").count(), 1);
+        assert!(!scrambled.contains("BBB"));
+
+        let hail = scramble(
+            24,
+            "19 13 30 @ -2  1 -2\n18, 19, 22 @ -1, -1, -2\n",
+            &mut rng,
+        )
+        .unwrap()
+        .unwrap();
+        assert!(hail.contains("@ -2, 1, -2\n") && hail.contains("@ -1, -1, -2\n"));
+
+        let wiring = "jqt: rhn xhk nvd\nrsh: frs pzl lsr\n";
+        let scrambled = scramble(25, wiring, &mut rng).unwrap().unwrap();
+        assert_eq!(sorted_words(&scrambled), sorted_words(wiring));
+        assert!(!scrambled.contains("jqt"));
+
+        assert!(scramble(1, "", &mut rng).is_none());
+        let error = scramble(7, "32T3X 765\n", &mut rng).unwrap().unwrap_err();
+        assert_eq!((error.line, error.column), (1, 1));
+        let error = scramble(7, "KKKKK 1\nKKKKK 2\n", &mut rng)
+            .unwrap()
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: `KKKKK` is already a hand"
+        );
+    }
+}
@@ -0,0 +1,456 @@
+//! A small HTTP API over the solvers, for tools and web pages on this machine.
+//!
+//! ```text
+//! GET  /days               every day and part
+//! POST /days/DAY/PART      solve the request body, answer as JSON
+//! ```
+//!
+//! Each connection carries one request. Bodies over the size limit are turned
+//! away before they're read, and every solve, and the parse step before it,
+//! runs under the same budget as `runner run`. A solver that ignores being
+//! cancelled keeps its thread after its request has had its answer, so
+//! connections past the limit are turned away, as is a day and part whose
+//! last solve is still going.
+
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use aoc::parse::ParseError;
+
+use crate::solvers::{self, Solver};
+use crate::watchdog::{self, Budget, Outcome};
+
+/// How long a client may take to send its request.
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+/// How long a connection turned away for being one too many is given to send
+/// its request, which holds up every other connection meanwhile.
+const BUSY_TIMEOUT: Duration = Duration::from_millis(100);
+/// The request line and headers together.
+const MAX_HEAD: usize = 16 << 10;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Limits {
+    pub budget: Budget,
+    /// Largest request body accepted, in bytes.
+    pub max_body: usize,
+    /// Most connections answered at once.
+    pub max_connections: usize,
+}
+
+pub struct Server {
+    listener: TcpListener,
+    limits: Limits,
+    in_flight: Arc<AtomicUsize>,
+}
+
+/// One connection being answered, counted in [`Server::in_flight`] until it's
+/// dropped.
+struct InFlight(Arc<AtomicUsize>);
+
+impl Drop for InFlight {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+struct Response {
+    status: u16,
+    body: String,
+}
+
+impl Response {
+    fn json(status: u16, body: String) -> Response {
+        Response { status, body }
+    }
+
+    fn error(status: u16, message: &str) -> Response {
+        Response::json(status, format!("{{\"error\":{}}}", json_string(message)))
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            408 => "Request Timeout",
+            411 => "Length Required",
+            413 => "Payload Too Large",
+            422 => "Unprocessable Entity",
+            431 => "Request Header Fields Too Large",
+            503 => "Service Unavailable",
+            504 => "Gateway Timeout",
+            507 => "Insufficient Storage",
+            _ => "Internal Server Error",
+        }
+    }
+
+    fn write_to(&self, stream: &mut TcpStream) -> io::Result<()> {
+        write!(
+            stream,
+            "HTTP/1.1 {} {}\r\n\
+             Content-Type: application/json\r\n\
+             Content-Length: {}\r\n\
+             Access-Control-Allow-Origin: *\r\n\
+             Connection: close\r\n\r\n{}",
+            self.status,
+            self.reason(),
+            self.body.len(),
+            self.body
+        )
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn list() -> Response {
+    let days: Vec<String> = solvers::SOLVERS
+        .iter()
+        .map(|solver| {
+            format!(
+                "{{\"day\":{},\"part\":{},\"version\":{}}}",
+                solver.day,
+                solver.part,
+                json_string(solver.version)
+            )
+        })
+        .collect();
+    Response::json(200, format!("[{}]", days.join(",")))
+}
+
+fn parse_error(error: &ParseError) -> String {
+    format!(
+        "{{\"line\":{},\"column\":{},\"message\":{}}}",
+        error.line,
+        error.column,
+        json_string(&error.message)
+    )
+}
+
+fn solve(solver: &Solver, body: &[u8], budget: Budget) -> Response {
+    let head = format!("\"day\":{},\"part\":{}", solver.day, solver.part);
+    if watchdog::still_running(solver) {
+        return Response::error(
+            503,
+            &format!(
+                "an earlier solve of day {} part {} is still running",
+                solver.day, solver.part
+            ),
+        );
+    }
+    let input = match aoc::input::decode(body) {
+        Ok(input) => input,
+        Err(e) => {
+            return Response::json(
+                400,
+                format!("{{{head},\"parse_error\":{}}}", parse_error(&e)),
+            )
+        }
+    };
+    let outcome = match watchdog::run_parse(solver, input.clone(), budget) {
+        Ok(Err(e)) => {
+            return Response::json(
+                422,
+                format!("{{{head},\"parse_error\":{}}}", parse_error(&e)),
+            )
+        }
+        // A parser that panics rather than failing is left to the solve to report.
+        Ok(Ok(())) | Err(Outcome::Panicked(_)) => watchdog::run(solver, input, budget),
+        Err(stopped) => stopped,
+    };
+    let status = match outcome {
+        Outcome::Solved { .. } => 200,
+        // Days with no parse step panic on input they can't make sense of.
+        Outcome::Panicked(_) => 422,
+        Outcome::TimedOut(_) => 504,
+        Outcome::OutOfMemory(_) => 507,
+    };
+    let body = match &outcome {
+        Outcome::Solved { answer, elapsed } => format!(
+            "{{{head},\"answer\":{},\"elapsed_ms\":{}}}",
+            json_string(answer),
+            elapsed.as_secs_f64() * 1000.0
+        ),
+        failed => format!("{{{head},\"error\":{}}}", json_string(&failed.to_string())),
+    };
+    Response::json(status, body)
+}
+
+/// Reads the request line and headers, one line at a time, up to [`MAX_HEAD`].
+fn read_head(reader: &mut impl BufRead) -> Result<Vec<String>, Response> {
+    let mut lines = vec![];
+    let mut read = 0;
+    loop {
+        let mut line = String::new();
+        let n = reader
+            .by_ref()
+            .take((MAX_HEAD - read + 1) as u64)
+            .read_line(&mut line)
+            .map_err(|_| Response::error(408, "request not received in time"))?;
+        read += n;
+        if read > MAX_HEAD {
+            return Err(Response::error(431, "request headers are too large"));
+        }
+        if n == 0 {
+            return Err(Response::error(400, "request ended early"));
+        }
+        let line = line.trim_end_matches(['\r', '\n']);
+        if line.is_empty() {
+            return Ok(lines);
+        }
+        lines.push(line.to_string());
+    }
+}
+
+fn handle(stream: &mut TcpStream, limits: Limits) -> Response {
+    let mut reader = BufReader::new(stream.try_clone().expect("socket can be cloned"));
+    let head = match read_head(&mut reader) {
+        Ok(head) => head,
+        Err(response) => return response,
+    };
+    let mut request_line = head.first().map_or("", String::as_str).split(' ');
+    let method = request_line.next().unwrap_or("");
+    let path = request_line.next().unwrap_or("");
+    let header = |name: &str| {
+        head[1..].iter().find_map(|line| {
+            let (key, value) = line.split_once(':')?;
+            key.eq_ignore_ascii_case(name).then(|| value.trim())
+        })
+    };
+
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    let solver = match (method, &segments[..]) {
+        ("GET", ["days"]) => return list(),
+        ("POST", ["days", day, part]) => {
+            let found = solvers::SOLVERS
+                .iter()
+                .find(|solver| day.parse() == Ok(solver.day) && part.parse() == Ok(solver.part));
+            match found {
+                Some(solver) => solver,
+                None => return Response::error(404, &format!("there is no solver for {path}")),
+            }
+        }
+        (_, ["days"] | ["days", _, _]) => return Response::error(405, "method not allowed"),
+        _ => return Response::error(404, &format!("nothing at {path}")),
+    };
+
+    if header("Transfer-Encoding").is_some() {
+        return Response::error(411, "send the input with a Content-Length");
+    }
+    let length: usize = match header("Content-Length").map(str::parse) {
+        Some(Ok(length)) => length,
+        Some(Err(_)) => return Response::error(400, "Content-Length is not a number"),
+        None => return Response::error(411, "send the input with a Content-Length"),
+    };
+    if length > limits.max_body {
+        return Response::error(
+            413,
+            &format!("input is over the limit of {} bytes", limits.max_body),
+        );
+    }
+    if header("Expect").is_some_and(|expect| expect.eq_ignore_ascii_case("100-continue")) {
+        let _ = stream.write_all(b"HTTP/1.1 100 Continue\r\n\r\n");
+    }
+    let mut body = vec![0; length];
+    if reader.read_exact(&mut body).is_err() {
+        return Response::error(408, "input not received in time");
+    }
+    solve(solver, &body, limits.budget)
+}
+
+impl Server {
+    pub fn bind(addr: &str, limits: Limits) -> io::Result<Server> {
+        Ok(Server {
+            listener: TcpListener::bind(addr)?,
+            limits,
+            in_flight: Arc::new(AtomicUsize::new(0)),
+        })
+    }
+
+    pub fn local_addr(&self) -> io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Answers requests until the listener fails, each on its own thread, up
+    /// to [`Limits::max_connections`] at once.
+    pub fn serve(&self) -> io::Result<()> {
+        for stream in self.listener.incoming() {
+            let mut stream = match stream {
+                Ok(stream) => stream,
+                // The client gave up before we got to it.
+                Err(e) if e.kind() == io::ErrorKind::ConnectionAborted => continue,
+                Err(e) => return Err(e),
+            };
+            if self.in_flight.fetch_add(1, Ordering::SeqCst) >= self.limits.max_connections {
+                self.in_flight.fetch_sub(1, Ordering::SeqCst);
+                // Closing with the request unread would reset the connection
+                // before the client saw why, so the head is read first, if
+                // it comes quickly.
+                let _ = stream.set_read_timeout(Some(BUSY_TIMEOUT));
+                let _ = stream.set_write_timeout(Some(BUSY_TIMEOUT));
+                let _ = read_head(&mut BufReader::new(&stream));
+                let busy = Response::error(503, "too many requests at once, try again later");
+                let _ = busy.write_to(&mut stream);
+                continue;
+            }
+            let in_flight = InFlight(Arc::clone(&self.in_flight));
+            let limits = self.limits;
+            thread::spawn(move || {
+                let _in_flight = in_flight;
+                let _ = stream.set_read_timeout(Some(READ_TIMEOUT));
+                let response = handle(&mut stream, limits);
+                let _ = response.write_to(&mut stream);
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(addr: SocketAddr, request: &str) -> (u16, String) {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream.write_all(request.as_bytes()).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let status = response[9..12].parse().unwrap();
+        let body = response.split_once("\r\n\r\n").unwrap().1.to_string();
+        (status, body)
+    }
+
+    fn post(addr: SocketAddr, path: &str, body: &str) -> (u16, String) {
+        request(
+            addr,
+            &format!(
+                "POST {path} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{body}",
+                body.len()
+            ),
+        )
+    }
+
+    #[test]
+    fn solves_over_localhost() {
+        let server = Server::bind(
+            "127.0.0.1:0",
+            Limits {
+                budget: Budget {
+                    time: Duration::from_secs(1),
+                    memory: 64 << 20,
+                },
+                max_body: 64,
+                max_connections: 8,
+            },
+        )
+        .unwrap();
+        let addr = server.local_addr().unwrap();
+        thread::spawn(move || server.serve());
+
+        let (status, body) = request(addr, "GET /days HTTP/1.1\r\n\r\n");
+        assert_eq!(status, 200);
+        assert!(body.starts_with("[{\"day\":1,\"part\":1,"));
+
+        let (status, body) = post(addr, "/days/15/1", "HASH\r\n");
+        assert_eq!(status, 200);
+        assert!(body.starts_with("{\"day\":15,\"part\":1,\"answer\":\"52\",\"elapsed_ms\":"));
+
+        let (status, body) = post(addr, "/days/6/1", "\u{0}\u{0}");
+        assert_eq!(status, 422, "{body}");
+        assert_eq!(
+            body,
+            "{\"day\":6,\"part\":1,\"parse_error\":\
+             {\"line\":1,\"column\":1,\"message\":\"expected `:`\"}}"
+        );
+        let (status, body) = post(addr, "/days/9/2", "1 2 3\n4 x 6");
+        assert_eq!(status, 422, "{body}");
+        assert_eq!(
+            body,
+            "{\"day\":9,\"part\":2,\"parse_error\":\
+             {\"line\":2,\"column\":3,\"message\":\"expected an integer\"}}"
+        );
+
+        let (status, _) = post(addr, "/days/6/1", &"1".repeat(65));
+        assert_eq!(status, 413);
+        assert_eq!(post(addr, "/days/26/1", "").0, 404);
+        assert_eq!(request(addr, "DELETE /days HTTP/1.1\r\n\r\n").0, 405);
+
+        let mut bad_utf8 = TcpStream::connect(addr).unwrap();
+        bad_utf8
+            .write_all(b"POST /days/1/1 HTTP/1.1\r\nContent-Length: 2\r\n\r\n\xff\xff")
+            .unwrap();
+        let mut response = String::new();
+        bad_utf8.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 400"));
+        assert!(response.contains("\"parse_error\":{\"line\":1,\"column\":1,"));
+    }
+
+    #[test]
+    fn busy_servers_turn_requests_away() {
+        let budget = Budget {
+            time: Duration::from_millis(50),
+            memory: 64 << 20,
+        };
+        let server = Server::bind(
+            "127.0.0.1:0",
+            Limits {
+                budget,
+                max_body: 64,
+                max_connections: 1,
+            },
+        )
+        .unwrap();
+        let addr = server.local_addr().unwrap();
+        thread::spawn(move || server.serve());
+
+        // Connected but not yet sent, which takes up the only place.
+        let idle = TcpStream::connect(addr).unwrap();
+        thread::sleep(Duration::from_millis(100));
+        let (status, body) = request(addr, "GET /days HTTP/1.1\r\n\r\n");
+        assert_eq!(status, 503, "{body}");
+        drop(idle);
+        let answered = (0..50).any(|_| {
+            thread::sleep(Duration::from_millis(20));
+            request(addr, "GET /days HTTP/1.1\r\n\r\n").0 == 200
+        });
+        assert!(answered);
+
+        // Left behind after its budget, and still going for the next request.
+        let stubborn = Solver {
+            day: 98,
+            part: 1,
+            version: "1",
+            input: "",
+            example: None,
+            parse: None,
+            solve: |_, _| {
+                thread::sleep(Duration::from_secs(3));
+                String::new()
+            },
+        };
+        assert_eq!(solve(&stubborn, b"", budget).status, 504);
+        let busy = solve(&stubborn, b"", budget);
+        assert_eq!(busy.status, 503);
+        assert!(busy.body.contains("day 98 part 1 is still running"));
+    }
+}
@@ -0,0 +1,530 @@
+//! Every solver the runner knows about, with where its real input lives.
+
+use aoc::cancel::Cancel;
+use aoc::parse;
+
+pub struct Solver {
+    pub day: u8,
+    pub part: u8,
+    /// Bumped whenever a change to the solver could change its answer, so
+    /// answers cached from the old code are no longer used.
+    pub version: &'static str,
+    /// The real input, relative to the repository root.
+    pub input: &'static str,
+    /// The example from the puzzle text, if the day has one.
+    pub example: Option<&'static str>,
+    /// Reads the input the way `solve` does without solving it, so input it
+    /// can't read is reported by line and column instead of as a panic.
+    /// `None` for days whose reading can't fail.
+    pub parse: Option<fn(&str) -> parse::Result<()>>,
+    pub solve: fn(&str, &Cancel) -> String,
+}
+
+pub const SOLVERS: &[Solver] = &[
+    Solver {
+        day: 1,
+        part: 1,
+        version: "1",
+        input: "day1/src/bin/input1.txt",
+        example: Some("day1/src/bin/example1.txt"),
+        parse: None,
+        solve: |input, _| day1::part1::part1(input),
+    },
+    Solver {
+        day: 1,
+        part: 2,
+        version: "1",
+        input: "day1/src/bin/input2.txt",
+        example: Some("day1/src/bin/example2.txt"),
+        parse: None,
+        solve: |input, _| day1::part2::part2(input),
+    },
+    Solver {
+        day: 2,
+        part: 1,
+        version: "1",
+        input: "day2/src/bin/input1.txt",
+        example: Some("day2/src/bin/example.txt"),
+        parse: Some(|input| day2::cubes::parse(input).map(drop).map_err(Into::into)),
+        solve: |input, _| day2::part1::part1(input),
+    },
+    Solver {
+        day: 2,
+        part: 2,
+        version: "2",
+        input: "day2/src/bin/input2.txt",
+        example: Some("day2/src/bin/example.txt"),
+        parse: Some(|input| day2::cubes::parse(input).map(drop).map_err(Into::into)),
+        solve: |input, _| day2::part2::part2(input),
+    },
+    Solver {
+        day: 3,
+        part: 1,
+        version: "1",
+        input: "day3/src/bin/input.txt",
+        example: Some("day3/src/bin/example.txt"),
+        parse: Some(|input| day3::schematic::Schematic::parse(input).map(drop)),
+        solve: |input, _| day3::part1::part1(input),
+    },
+    Solver {
+        day: 3,
+        part: 2,
+        version: "2",
+        input: "day3/src/bin/input.txt",
+        example: Some("day3/src/bin/example.txt"),
+        parse: Some(|input| day3::schematic::Schematic::parse(input).map(drop)),
+        solve: |input, _| day3::part2::part2(input),
+    },
+    Solver {
+        day: 4,
+        part: 1,
+        version: "1",
+        input: "day4/src/bin/input.txt",
+        example: Some("day4/src/bin/example.txt"),
+        parse: Some(|input| day4::cascade::parse(input).map(drop)),
+        solve: |input, _| day4::part1::part1(input),
+    },
+    Solver {
+        day: 4,
+        part: 2,
+        version: "1",
+        input: "day4/src/bin/input.txt",
+        example: Some("day4/src/bin/example.txt"),
+        parse: Some(|input| day4::cascade::parse(input).map(drop)),
+        solve: |input, _| day4::part2::part2(input),
+    },
+    Solver {
+        day: 5,
+        part: 1,
+        version: "1",
+        input: "day5/src/bin/input.txt",
+        example: Some("day5/src/bin/example.txt"),
+        parse: Some(day5::part1::parse),
+        solve: |input, _| day5::part1::part1(input),
+    },
+    Solver {
+        day: 5,
+        part: 2,
+        version: "2",
+        input: "day5/src/bin/input.txt",
+        example: Some("day5/src/bin/example.txt"),
+        parse: Some(day5::part2::parse),
+        solve: |input, _| day5::part2::part2(input),
+    },
+    Solver {
+        day: 6,
+        part: 1,
+        version: "2",
+        input: "day6/src/bin/input.txt",
+        example: Some("day6/src/bin/example.txt"),
+        parse: Some(day6::part1::parse),
+        solve: |input, _| day6::part1::part1(input),
+    },
+    Solver {
+        day: 6,
+        part: 2,
+        version: "2",
+        input: "day6/src/bin/input.txt",
+        example: Some("day6/src/bin/example.txt"),
+        parse: Some(day6::part2::parse),
+        solve: |input, _| day6::part2::part2(input),
+    },
+    Solver {
+        day: 7,
+        part: 1,
+        version: "1",
+        input: "day7/src/bin/input.txt",
+        example: Some("day7/src/bin/example.txt"),
+        parse: Some(day7::part1::parse),
+        solve: |input, _| day7::part1::part1(input),
+    },
+    Solver {
+        day: 7,
+        part: 2,
+        version: "1",
+        input: "day7/src/bin/input.txt",
+        example: Some("day7/src/bin/example.txt"),
+        parse: Some(day7::part2::parse),
+        solve: |input, _| day7::part2::part2(input),
+    },
+    Solver {
+        day: 8,
+        part: 1,
+        version: "1",
+        input: "day8/src/bin/input.txt",
+        example: Some("day8/src/bin/example1.txt"),
+        parse: Some(day8::part1::parse),
+        solve: |input, _| day8::part1::part1(input),
+    },
+    Solver {
+        day: 8,
+        part: 2,
+        version: "1",
+        input: "day8/src/bin/input.txt",
+        example: Some("day8/src/bin/example2.txt"),
+        parse: Some(day8::part2::parse),
+        solve: |input, _| day8::part2::part2(input),
+    },
+    Solver {
+        day: 9,
+        part: 1,
+        version: "1",
+        input: "day9/src/bin/input.txt",
+        example: Some("day9/src/bin/example.txt"),
+        parse: Some(day9::part1::parse),
+        solve: |input, _| day9::part1::process(input),
+    },
+    Solver {
+        day: 9,
+        part: 2,
+        version: "1",
+        input: "day9/src/bin/input.txt",
+        example: Some("day9/src/bin/example.txt"),
+        parse: Some(day9::part2::parse),
+        solve: |input, _| day9::part2::process(input),
+    },
+    Solver {
+        day: 10,
+        part: 1,
+        version: "1",
+        input: "day10/src/bin/input.txt",
+        example: Some("day10/src/bin/example1.txt"),
+        parse: None,
+        solve: |input, _| day10::part1::process(input),
+    },
+    Solver {
+        day: 10,
+        part: 2,
+        version: "1",
+        input: "day10/src/bin/input.txt",
+        example: Some("day10/src/bin/example2.txt"),
+        parse: None,
+        solve: |input, _| day10::part2::process(input, day10::part2::Direction::Up),
+    },
+    Solver {
+        day: 11,
+        part: 1,
+        version: "1",
+        input: "day11/src/bin/input.txt",
+        example: Some("day11/src/bin/example.txt"),
+        parse: None,
+        solve: |input, _| day11::part1::process(input),
+    },
+    Solver {
+        day: 11,
+        part: 2,
+        version: "1",
+        input: "day11/src/bin/input.txt",
+        example: Some("day11/src/bin/example.txt"),
+        parse: None,
+        solve: |input, _| day11::part2::process(input),
+    },
+    Solver {
+        day: 12,
+        part: 1,
+        version: "1",
+        input: "day12/src/bin/input.txt",
+        example: Some("day12/src/bin/example1.txt"),
+        parse: Some(day12::part1::parse),
+        solve: |input, _| day12::part1::process(input),
+    },
+    Solver {
+        day: 12,
+        part: 2,
+        version: "1",
+        input: "day12/src/bin/input.txt",
+        example: Some("day12/src/bin/example2.txt"),
+        parse: Some(day12::part2::parse),
+        solve: |input, _| day12::part2::process(input),
+    },
+    Solver {
+        day: 13,
+        part: 1,
+        version: "1",
+        input: "day13/src/bin/input.txt",
+        example: Some("day13/src/bin/example.txt"),
+        parse: None,
+        solve: |input, _| day13::part1::process(input),
+    },
+    Solver {
+        day: 13,
+        part: 2,
+        version: "1",
+        input: "day13/src/bin/input.txt",
+        example: Some("day13/src/bin/example.txt"),
+        parse: None,
+        solve: |input, _| day13::part2::process(input),
+    },
+    Solver {
+        day: 14,
+        part: 1,
+        version: "1",
+        input: "day14/src/bin/input.txt",
+        example: Some("day14/src/bin/example1.txt"),
+        parse: None,
+        solve: |input, _| day14::part1::process(input),
+    },
+    Solver {
+        day: 14,
+        part: 2,
+        version: "1",
+        input: "day14/src/bin/input.txt",
+        example: Some("day14/src/bin/example2.txt"),
+        parse: None,
+        solve: |input, _| day14::part2::process(input),
+    },
+    Solver {
+        day: 15,
+        part: 1,
+        version: "1",
+        input: "day15/src/bin/input.txt",
+        example: Some("day15/src/bin/example.txt"),
+        parse: None,
+        solve: |input, _| day15::part1::process(input),
+    },
+    Solver {
+        day: 15,
+        part: 2,
+        version: "1",
+        input: "day15/src/bin/input.txt",
+        example: Some("day15/src/bin/example.txt"),
+        parse: Some(day15::part2::parse),
+        solve: |input, _| day15::part2::process(input),
+    },
+    Solver {
+        day: 16,
+        part: 1,
+        version: "1",
+        input: "day16/src/bin/input.txt",
+        example: Some("day16/src/bin/example.txt"),
+        parse: None,
+        solve: |input, _| day16::part1::process(input),
+    },
+    Solver {
+        day: 16,
+        part: 2,
+        version: "1",
+        input: "day16/src/bin/input.txt",
+        example: Some("day16/src/bin/example.txt"),
+        parse: None,
+        solve: |input, _| day16::part2::process(input),
+    },
+    Solver {
+        day: 17,
+        part: 1,
+        version: "1",
+        input: "day17/src/bin/input.txt",
+        example: Some("day17/src/bin/t1.txt"),
+        parse: None,
+        solve: day17::part1::process,
+    },
+    Solver {
+        day: 17,
+        part: 2,
+        version: "1",
+        input: "day17/src/bin/input.txt",
+        example: None,
+        parse: None,
+        solve: |input, _| day17::part2::process(input),
+    },
+    Solver {
+        day: 18,
+        part: 1,
+        version: "1",
+        input: "day18/src/bin/input.txt",
+        example: None,
+        parse: None,
+        solve: |input, _| day18::part1::process(input),
+    },
+    Solver {
+        day: 18,
+        part: 2,
+        version: "1",
+        input: "day18/src/bin/input.txt",
+        example: None,
+        parse: None,
+        solve: |input, _| day18::part2::process(input),
+    },
+    Solver {
+        day: 19,
+        part: 1,
+        version: "1",
+        input: "day19/src/bin/input.txt",
+        example: None,
+        parse: None,
+        solve: |input, _| day19::part1::process(input),
+    },
+    Solver {
+        day: 19,
+        part: 2,
+        version: "1",
+        input: "day19/src/bin/input.txt",
+        example: None,
+        parse: None,
+        solve: |input, _| day19::part2::process(input),
+    },
+    Solver {
+        day: 20,
+        part: 1,
+        version: "1",
+        input: "day20/src/bin/input.txt",
+        example: None,
+        parse: None,
+        solve: |input, _| day20::part1::process(input),
+    },
+    Solver {
+        day: 20,
+        part: 2,
+        version: "1",
+        input: "day20/src/bin/input.txt",
+        example: None,
+        parse: None,
+        solve: |input, _| day20::part2::process(input),
+    },
+    Solver {
+        day: 21,
+        part: 1,
+        version: "1",
+        input: "day21/src/bin/input.txt",
+        example: None,
+        parse: None,
+        solve: |input, _| day21::part1::process(input),
+    },
+    Solver {
+        day: 21,
+        part: 2,
+        version: "1",
+        input: "day21/src/bin/input.txt",
+        example: None,
+        parse: None,
+        solve: |input, _| day21::part2::process(input),
+    },
+    Solver {
+        day: 22,
+        part: 1,
+        version: "1",
+        input: "day22/src/bin/input.txt",
+        example: None,
+        parse: None,
+        solve: |input, _| day22::part1::process(input),
+    },
+    Solver {
+        day: 22,
+        part: 2,
+        version: "1",
+        input: "day22/src/bin/input.txt",
+        example: None,
+        parse: None,
+        solve: |input, _| day22::part2::process(input),
+    },
+    Solver {
+        day: 23,
+        part: 1,
+        version: "1",
+        input: "day23/src/bin/input.txt",
+        example: Some("day23/src/bin/example.txt"),
+        parse: None,
+        solve: |input, _| day23::part1::process(input),
+    },
+    Solver {
+        day: 23,
+        part: 2,
+        version: "1",
+        input: "day23/src/bin/input.txt",
+        example: Some("day23/src/bin/example.txt"),
+        parse: None,
+        solve: day23::part2::process,
+    },
+    Solver {
+        day: 24,
+        part: 1,
+        version: "1",
+        input: "day24/src/bin/input.txt",
+        example: Some("day24/src/bin/t1.txt"),
+        parse: Some(day24::part1::parse),
+        solve: |input, _| day24::part1::process(input, 200_000_000_000_000, 400_000_000_000_000),
+    },
+    Solver {
+        day: 24,
+        part: 2,
+        version: "1",
+        input: "day24/src/bin/input.txt",
+        example: None,
+        parse: None,
+        solve: |input, _| day24::part2::process(input),
+    },
+    Solver {
+        day: 25,
+        part: 1,
+        version: "1",
+        input: "day25/src/bin/input.txt",
+        example: None,
+        parse: None,
+        solve: |input, _| day25::part1::process(input),
+    },
+    Solver {
+        day: 25,
+        part: 2,
+        version: "1",
+        input: "day25/src/bin/input.txt",
+        example: None,
+        parse: None,
+        solve: |input, _| day25::part2::process(input),
+    },
+];
+
+/// The solvers picked out by `DAY` or `DAY:PART` targets, in order. No
+/// targets means all of them.
+pub fn select(targets: &[String]) -> Result<Vec<&'static Solver>, String> {
+    if targets.is_empty() {
+        return Ok(SOLVERS.iter().collect());
+    }
+    let mut selected = vec![];
+    for target in targets {
+        let (day, part) = match target.split_once(':') {
+            Some((day, part)) => (day, Some(part)),
+            None => (target.as_str(), None),
+        };
+        let day: u8 = day
+            .parse()
+            .map_err(|_| format!("`{target}` should look like DAY or DAY:PART"))?;
+        let part: Option<u8> = match part {
+            Some(part) => Some(
+                part.parse()
+                    .map_err(|_| format!("`{target}` should look like DAY or DAY:PART"))?,
+            ),
+            None => None,
+        };
+        let before = selected.len();
+        selected.extend(
+            SOLVERS
+                .iter()
+                .filter(|solver| solver.day == day && part.is_none_or(|part| solver.part == part)),
+        );
+        if selected.len() == before {
+            return Err(format!("there is no solver for `{target}`"));
+        }
+    }
+    Ok(selected)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_day_has_two_parts() {
+        for day in 1..=25 {
+            let parts: Vec<u8> = SOLVERS
+                .iter()
+                .filter(|solver| solver.day == day)
+                .map(|solver| solver.part)
+                .collect();
+            assert_eq!(parts, vec![1, 2], "day {day}");
+        }
+        let picked = select(&["3".to_string(), "23:2".to_string()]).unwrap();
+        let picked: Vec<(u8, u8)> = picked.iter().map(|s| (s.day, s.part)).collect();
+        assert_eq!(picked, vec![(3, 1), (3, 2), (23, 2)]);
+        assert!(select(&["26".to_string()]).is_err());
+    }
+}
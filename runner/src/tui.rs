@@ -0,0 +1,461 @@
+//! `runner tui`: pick a day, run a part on the example or the real input, and
+//! watch the simulation play back for the days that trace one.
+//!
+//! Solvers record their animation through [`aoc::trace`], the same trace the
+//! `trace` viewer replays, so every frame is one of the days' own text
+//! renderings. The solver runs in the background under the usual budget, and
+//! its trace is read back once it's done.
+
+use std::fs::{self, File};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc::trace::{Event, Trace};
+use crossterm::event::{self, Event as TermEvent, KeyCode, KeyEventKind};
+use crossterm::terminal::{self, EnterAlternateScreen, LeaveAlternateScreen};
+use ratatui::backend::CrosstermBackend;
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, List, ListState, Paragraph};
+use ratatui::{Frame, Terminal};
+
+use crate::catalogue;
+use crate::mute::Muted;
+use crate::solvers::{self, Solver};
+use crate::watchdog::{self, Budget, Outcome};
+
+/// How often the screen is redrawn, and so how fast animations step.
+const TICK: Duration = Duration::from_millis(40);
+/// Only this much of a trace is read back; a long run on the real input can
+/// record far more than is worth watching.
+const MAX_TRACE: usize = 64 << 20;
+const HELP: &str = "↑↓ day  e input  a animate  1 2 run  space play  ←→ step  +- speed  q quit";
+
+struct Animation {
+    trace: Trace,
+    /// The steps that redrew the state.
+    frames: Vec<usize>,
+    at: usize,
+    playing: bool,
+    /// Frames advanced per tick.
+    speed: usize,
+    scroll: u16,
+    cut_short: bool,
+}
+
+/// The first `limit` bytes of the trace at `path`, cut back to the last whole
+/// line, and whether there was more than that.
+fn read_trace(path: &Path, limit: usize) -> io::Result<(String, bool)> {
+    use std::io::Read;
+
+    let mut bytes = vec![];
+    File::open(path)?
+        .take(limit as u64 + 1)
+        .read_to_end(&mut bytes)?;
+    let cut_short = bytes.len() > limit;
+    if cut_short {
+        // The cut may land mid-line or mid-char.
+        let end = bytes[..limit]
+            .iter()
+            .rposition(|&b| b == b'\n')
+            .unwrap_or(0);
+        bytes.truncate(end);
+    }
+    let text =
+        String::from_utf8(bytes).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    Ok((text, cut_short))
+}
+
+impl Animation {
+    fn load(text: &str, cut_short: bool) -> Result<Option<Animation>, String> {
+        let trace = Trace::parse(text).map_err(|e| format!("can't read the trace: {e}"))?;
+        let frames: Vec<usize> = (0..trace.len())
+            .filter(|&i| matches!(trace.steps[i].event, Event::State(_)))
+            .collect();
+        if frames.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(Animation {
+            trace,
+            frames,
+            at: 0,
+            playing: true,
+            speed: 1,
+            scroll: 0,
+            cut_short,
+        }))
+    }
+
+    fn step(&mut self, by: isize) {
+        let last = self.frames.len() - 1;
+        self.at = self.at.saturating_add_signed(by).min(last);
+        if self.at == last {
+            self.playing = false;
+        }
+    }
+
+    /// The last note recorded at or before the current frame.
+    fn note(&self) -> Option<&str> {
+        self.trace.steps[..=self.frames[self.at]]
+            .iter()
+            .rev()
+            .find_map(|step| match &step.event {
+                Event::Note(note) => Some(note.as_str()),
+                _ => None,
+            })
+    }
+
+    fn draw(&mut self, frame: &mut Frame, area: ratatui::layout::Rect) {
+        let step = &self.trace.steps[self.frames[self.at]];
+        let changed = match &step.event {
+            Event::State(changed) => changed.as_slice(),
+            _ => &[],
+        };
+        // Follow the action when the grid is taller than the screen.
+        let height = area.height.saturating_sub(2);
+        if let Some(&first) = changed.iter().min() {
+            let first = first as u16;
+            if first < self.scroll || first >= self.scroll + height {
+                self.scroll = first.saturating_sub(height / 2);
+            }
+        }
+        let lines: Vec<Line> = step
+            .rows
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let style = if changed.contains(&i) {
+                    Style::new().fg(Color::Yellow)
+                } else {
+                    Style::new()
+                };
+                Line::styled(row.to_string(), style)
+            })
+            .collect();
+        let mut title = format!(
+            " frame {}/{}  queue {}  x{}{} ",
+            self.at + 1,
+            self.frames.len(),
+            step.queue_len,
+            self.speed,
+            if self.playing { "" } else { "  paused" }
+        );
+        if let Some(note) = self.note() {
+            title += &format!(" {note} ");
+        }
+        if self.cut_short {
+            title += " (trace cut short) ";
+        }
+        frame.render_widget(
+            Paragraph::new(lines)
+                .scroll((self.scroll, 0))
+                .block(Block::bordered().title(title)),
+            area,
+        );
+    }
+}
+
+struct Running {
+    solver: &'static Solver,
+    started: Instant,
+    done: Receiver<Outcome>,
+}
+
+struct App {
+    days: Vec<u8>,
+    /// `Day N: Title` for each of `days`.
+    headings: Vec<String>,
+    list: ListState,
+    example: bool,
+    animate: bool,
+    budget: Budget,
+    trace_path: PathBuf,
+    running: Option<Running>,
+    result: String,
+    animation: Option<Animation>,
+}
+
+impl App {
+    fn day(&self) -> u8 {
+        self.days[self.list.selected().unwrap_or(0)]
+    }
+
+    fn input_path(&self, solver: &Solver) -> Option<&'static str> {
+        if self.example {
+            solver.example
+        } else {
+            Some(solver.input)
+        }
+    }
+
+    fn start(&mut self, part: u8) {
+        if self.running.is_some() {
+            return;
+        }
+        let day = self.day();
+        let Some(solver) = solvers::SOLVERS
+            .iter()
+            .find(|solver| solver.day == day && solver.part == part)
+        else {
+            return;
+        };
+        let Some(path) = self.input_path(solver) else {
+            self.result = format!("day {day} part {part} has no example");
+            return;
+        };
+        let input = match crate::read_input(&crate::repo_root().join(path)) {
+            Ok(input) => input,
+            Err(e) => {
+                self.result = e;
+                return;
+            }
+        };
+
+        self.animation = None;
+        let _ = fs::remove_file(&self.trace_path);
+        let (send, done) = mpsc::channel();
+        let budget = self.budget;
+        let trace = self.animate.then(|| self.trace_path.clone());
+        thread::spawn(move || {
+            let _ = send.send(watchdog::run_traced(solver, input, budget, trace));
+        });
+        self.running = Some(Running {
+            solver,
+            started: Instant::now(),
+            done,
+        });
+    }
+
+    fn poll(&mut self) {
+        let Some(running) = &self.running else {
+            return;
+        };
+        let outcome = match running.done.try_recv() {
+            Ok(outcome) => outcome,
+            Err(TryRecvError::Empty) => return,
+            Err(TryRecvError::Disconnected) => Outcome::Panicked("runner thread died".to_string()),
+        };
+        let solver = running.solver;
+        self.running = None;
+        self.result = format!("part {}: {outcome}", solver.part);
+        if self.animate {
+            // A solver that doesn't trace never creates the file.
+            if let Ok((text, cut_short)) = read_trace(&self.trace_path, MAX_TRACE) {
+                match Animation::load(&text, cut_short) {
+                    Ok(animation) => self.animation = animation,
+                    Err(e) => self.result += &format!("  ({e})"),
+                }
+            }
+        }
+    }
+
+    fn key(&mut self, code: KeyCode) -> bool {
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => return false,
+            KeyCode::Up | KeyCode::Char('k') => self.list.select_previous(),
+            KeyCode::Down | KeyCode::Char('j') => self.list.select_next(),
+            KeyCode::Char('e') => {
+                self.example = !self.example;
+                // Real inputs can trace for a very long time.
+                self.animate = self.example;
+            }
+            KeyCode::Char('a') => self.animate = !self.animate,
+            KeyCode::Char('1') => self.start(1),
+            KeyCode::Char('2') => self.start(2),
+            _ => (),
+        }
+        if let Some(animation) = &mut self.animation {
+            match code {
+                KeyCode::Char(' ') => {
+                    if animation.at + 1 == animation.frames.len() {
+                        animation.at = 0;
+                    }
+                    animation.playing = !animation.playing;
+                }
+                KeyCode::Left => {
+                    animation.playing = false;
+                    animation.step(-1);
+                }
+                KeyCode::Right => {
+                    animation.playing = false;
+                    animation.step(1);
+                }
+                KeyCode::Home => animation.at = 0,
+                KeyCode::End => animation.step(isize::MAX),
+                KeyCode::Char('+') | KeyCode::Char('=') => {
+                    animation.speed = (animation.speed * 2).min(1 << 16);
+                }
+                KeyCode::Char('-') => animation.speed = (animation.speed / 2).max(1),
+                _ => (),
+            }
+        }
+        true
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [days, main] =
+            Layout::horizontal([Constraint::Length(12), Constraint::Min(0)]).areas(frame.area());
+        let [info, grid] =
+            Layout::vertical([Constraint::Length(6), Constraint::Min(0)]).areas(main);
+
+        let items: Vec<String> = self.days.iter().map(|day| format!("day {day}")).collect();
+        let list = List::new(items)
+            .block(Block::bordered().title(" days "))
+            .highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+        frame.render_stateful_widget(list, days, &mut self.list);
+
+        let heading = &self.headings[self.list.selected().unwrap_or(0)];
+        let input = if self.example { "example" } else { "real" };
+        let status = match &self.running {
+            Some(running) => format!(
+                "running part {}… {:.1?}",
+                running.solver.part,
+                running.started.elapsed()
+            ),
+            None => self.result.clone(),
+        };
+        let lines = vec![
+            Line::from(vec![
+                Span::styled(heading.clone(), Style::new().add_modifier(Modifier::BOLD)),
+                Span::raw(format!(
+                    "  {input} input  animation {}",
+                    if self.animate { "on" } else { "off" }
+                )),
+            ]),
+            Line::raw(status),
+            Line::raw(""),
+            Line::styled(HELP, Style::new().fg(Color::DarkGray)),
+        ];
+        frame.render_widget(Paragraph::new(lines).block(Block::bordered()), info);
+
+        match &mut self.animation {
+            Some(animation) => animation.draw(frame, grid),
+            None => frame.render_widget(
+                Paragraph::new("nothing to animate").block(Block::bordered()),
+                grid,
+            ),
+        }
+    }
+
+    fn tick(&mut self) {
+        if let Some(animation) = &mut self.animation {
+            if animation.playing {
+                animation.step(animation.speed as isize);
+            }
+        }
+    }
+}
+
+/// The terminal, drawn through a copy of stdout while the process is muted,
+/// so that solvers' `println!`s and `dbg!`s don't scribble over the screen.
+struct Screen {
+    terminal: Terminal<CrosstermBackend<File>>,
+    // Dropped after the terminal, so stdout comes back once the screen is left.
+    _muted: Muted,
+}
+
+impl Screen {
+    fn open() -> io::Result<Screen> {
+        let (muted, mut tty) = Muted::start()?;
+        terminal::enable_raw_mode()?;
+        crossterm::execute!(tty, EnterAlternateScreen)?;
+        Ok(Screen {
+            terminal: Terminal::new(CrosstermBackend::new(tty))?,
+            _muted: muted,
+        })
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = terminal::disable_raw_mode();
+        let _ = crossterm::execute!(self.terminal.backend_mut(), LeaveAlternateScreen);
+        let _ = self.terminal.show_cursor();
+    }
+}
+
+fn event_loop(
+    terminal: &mut Terminal<CrosstermBackend<File>>,
+    app: &mut App,
+) -> std::io::Result<()> {
+    let mut next_tick = Instant::now() + TICK;
+    loop {
+        app.poll();
+        terminal.draw(|frame| app.draw(frame))?;
+        let wait = next_tick.saturating_duration_since(Instant::now());
+        if event::poll(wait)? {
+            if let TermEvent::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press && !app.key(key.code) {
+                    return Ok(());
+                }
+            }
+        }
+        if Instant::now() >= next_tick {
+            app.tick();
+            next_tick = Instant::now() + TICK;
+        }
+    }
+}
+
+pub fn run(budget: Budget) -> Result<bool, String> {
+    let mut days: Vec<u8> = solvers::SOLVERS.iter().map(|solver| solver.day).collect();
+    days.dedup();
+    let headings = days
+        .iter()
+        .map(|&day| match catalogue::load_day(crate::repo_root(), day) {
+            Some(puzzle) => puzzle.heading(),
+            None => format!("Day {day}"),
+        })
+        .collect();
+    let mut app = App {
+        days,
+        headings,
+        list: ListState::default().with_selected(Some(0)),
+        example: true,
+        animate: true,
+        budget,
+        trace_path: std::env::temp_dir().join(format!("runner-tui-{}.trace", std::process::id())),
+        running: None,
+        result: String::new(),
+        animation: None,
+    };
+    let mut screen = Screen::open().map_err(|e| format!("can't start the terminal: {e}"))?;
+    let result = event_loop(&mut screen.terminal, &mut app);
+    drop(screen);
+    let _ = fs::remove_file(&app.trace_path);
+    result.map_err(|e| e.to_string())?;
+    Ok(true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plays_back_a_trace() {
+        let text = "aoc-trace 1\nstate\t2\t0\t#.\t1\t..\nnote\tnorth\nstate\t2\t1\t.#\n";
+        let mut animation = Animation::load(text, false).unwrap().unwrap();
+        assert_eq!(animation.frames, vec![0, 2]);
+        assert_eq!(animation.note(), None);
+        animation.step(5);
+        assert_eq!(animation.at, 1);
+        assert!(!animation.playing);
+        assert_eq!(animation.note(), Some("north"));
+        assert!(Animation::load("aoc-trace 1\nnote\thi\n", false)
+            .unwrap()
+            .is_none());
+
+        // Cut to the limit and back to the line before, mid-char as it is.
+        let path = std::env::temp_dir().join(format!("runner-tui-{}.txt", std::process::id()));
+        fs::write(&path, "aoc-trace 1\nnote\té\n").unwrap();
+        let cut = read_trace(&path, 18).unwrap();
+        let whole = read_trace(&path, 20).unwrap();
+        let _ = fs::remove_file(&path);
+        assert_eq!(cut, ("aoc-trace 1".to_string(), true));
+        assert_eq!(whole, ("aoc-trace 1\nnote\té\n".to_string(), false));
+    }
+}
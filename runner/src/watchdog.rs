@@ -0,0 +1,337 @@
+//! Runs one solver on its own thread under a time and memory budget.
+//!
+//! When the solver goes over either budget its [`Cancel`] token is tripped.
+//! Solvers that check the token stop shortly after; ones that don't are given
+//! a moment and then left behind, still running, so the next solver can start;
+//! [`still_running`] says whether one left behind has finished since.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::PathBuf;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc::cancel::Cancel;
+use aoc::parse;
+use aoc::trace;
+
+use crate::meter::Meter;
+use crate::solvers::Solver;
+
+/// How often the watchdog looks at the clock and the meter.
+const POLL: Duration = Duration::from_millis(10);
+/// How long a cancelled solver has to notice before it is abandoned.
+const GRACE: Duration = Duration::from_secs(1);
+/// Some of the solvers recurse once per tile.
+const STACK_SIZE: usize = 512 << 20;
+
+/// How many solves left behind are still running, by thread name.
+static ABANDONED: Mutex<BTreeMap<String, usize>> = Mutex::new(BTreeMap::new());
+
+fn abandoned() -> MutexGuard<'static, BTreeMap<String, usize>> {
+    ABANDONED.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Whether a solve of `solver` that was left behind hasn't finished yet.
+pub fn still_running(solver: &Solver) -> bool {
+    abandoned().contains_key(&thread_name(solver))
+}
+
+/// What has become of a watched thread, decided under a lock so the thread
+/// finishing and the watchdog leaving it behind can't cross.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Fate {
+    Watched,
+    Finished,
+    Abandoned,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Budget {
+    pub time: Duration,
+    /// Live bytes the solver may hold.
+    pub memory: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Solved { answer: String, elapsed: Duration },
+    TimedOut(Duration),
+    OutOfMemory(usize),
+    Panicked(String),
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Solved { answer, elapsed } => write!(f, "{answer} ({elapsed:.2?})"),
+            Outcome::TimedOut(after) => write!(f, "timed out after {after:.2?}"),
+            Outcome::OutOfMemory(limit) => {
+                write!(f, "out of memory (over {} MiB)", limit >> 20)
+            }
+            Outcome::Panicked(message) => write!(f, "panicked: {message}"),
+        }
+    }
+}
+
+fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// The name of the thread `solver` runs on, which is what a panic hook sees.
+pub fn thread_name(solver: &Solver) -> String {
+    format!("day {} part {}", solver.day, solver.part)
+}
+
+pub fn run(solver: &Solver, input: String, budget: Budget) -> Outcome {
+    run_metered(solver, input, budget, Meter::new())
+}
+
+/// [`run`], with the solver tracing to `trace`, or not at all for `None`,
+/// whatever `AOC_TRACE` says.
+pub fn run_traced(
+    solver: &Solver,
+    input: String,
+    budget: Budget,
+    trace: Option<PathBuf>,
+) -> Outcome {
+    let solve = solver.solve;
+    solved(watch(solver, budget, Meter::new(), move |cancel| {
+        trace::tracing_to(trace.as_deref(), || solve(&input, cancel))
+    }))
+}
+
+/// [`run`], counting what the solver allocates on `meter`.
+pub fn run_metered(solver: &Solver, input: String, budget: Budget, meter: Meter) -> Outcome {
+    let solve = solver.solve;
+    solved(watch(solver, budget, meter, move |cancel| {
+        solve(&input, cancel)
+    }))
+}
+
+/// Reads `input` with `solver`'s parse step under `budget`, the way [`run`]
+/// solves it, or says why it was stopped. Without a parse step there's
+/// nothing to read.
+pub fn run_parse(
+    solver: &Solver,
+    input: String,
+    budget: Budget,
+) -> Result<parse::Result<()>, Outcome> {
+    let Some(parse) = solver.parse else {
+        return Ok(Ok(()));
+    };
+    watch(solver, budget, Meter::new(), move |_| parse(&input)).map(|(read, _)| read)
+}
+
+fn solved(result: Result<(String, Duration), Outcome>) -> Outcome {
+    match result {
+        Ok((answer, elapsed)) => Outcome::Solved { answer, elapsed },
+        Err(outcome) => outcome,
+    }
+}
+
+/// Runs `job` on `solver`'s thread under `budget`, returning what it returned
+/// and how long it took, or the outcome it was stopped with.
+fn watch<T: Send + 'static>(
+    solver: &Solver,
+    budget: Budget,
+    meter: Meter,
+    job: impl FnOnce(&Cancel) -> T + Send + 'static,
+) -> Result<(T, Duration), Outcome> {
+    let cancel = Cancel::new();
+    let (done, finished) = mpsc::channel();
+
+    let token = cancel.clone();
+    let name = thread_name(solver);
+    let fate = Arc::new(Mutex::new(Fate::Watched));
+    let watched = Arc::clone(&fate);
+    let start = Instant::now();
+    let spawned = thread::Builder::new()
+        .name(name.clone())
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            meter.attach();
+            let result = panic::catch_unwind(AssertUnwindSafe(|| job(&token)));
+            let mut fate = watched.lock().unwrap_or_else(|e| e.into_inner());
+            if *fate == Fate::Abandoned {
+                let mut abandoned = abandoned();
+                let name = thread::current().name().unwrap_or_default().to_string();
+                if let Some(count) = abandoned.get_mut(&name) {
+                    *count -= 1;
+                    if *count == 0 {
+                        abandoned.remove(&name);
+                    }
+                }
+            }
+            *fate = Fate::Finished;
+            drop(fate);
+            // The watchdog may have given up on us already.
+            let _ = done.send((result, start.elapsed()));
+        });
+    if let Err(e) = spawned {
+        return Err(Outcome::Panicked(format!("couldn't start a thread: {e}")));
+    }
+
+    let mut verdict = None;
+    let mut cancelled_at = None;
+    loop {
+        match finished.recv_timeout(POLL) {
+            Ok((result, elapsed)) => {
+                return match (verdict, result) {
+                    (Some(verdict), _) => Err(verdict),
+                    (None, Ok(value)) => Ok((value, elapsed)),
+                    (None, Err(payload)) => Err(Outcome::Panicked(panic_message(payload))),
+                };
+            }
+            Err(RecvTimeoutError::Disconnected) => {
+                return Err(
+                    verdict.unwrap_or_else(|| Outcome::Panicked("solver vanished".to_string()))
+                );
+            }
+            Err(RecvTimeoutError::Timeout) => (),
+        }
+
+        if verdict.is_none() {
+            if start.elapsed() > budget.time {
+                verdict = Some(Outcome::TimedOut(budget.time));
+            } else if meter.bytes() > budget.memory {
+                verdict = Some(Outcome::OutOfMemory(budget.memory));
+            }
+            if verdict.is_some() {
+                cancel.cancel();
+                cancelled_at = Some(Instant::now());
+            }
+        }
+        if cancelled_at.is_some_and(|at| at.elapsed() > GRACE) {
+            let mut fate = fate.lock().unwrap_or_else(|e| e.into_inner());
+            if *fate == Fate::Watched {
+                *fate = Fate::Abandoned;
+                *abandoned().entry(name).or_default() += 1;
+            }
+            return Err(verdict.expect("only cancelled with a verdict"));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solver(solve: fn(&str, &Cancel) -> String) -> Solver {
+        Solver {
+            day: 0,
+            part: 1,
+            version: "1",
+            input: "",
+            example: None,
+            parse: None,
+            solve,
+        }
+    }
+
+    #[test]
+    fn budgets_are_enforced() {
+        let budget = Budget {
+            time: Duration::from_millis(50),
+            memory: 64 << 20,
+        };
+
+        let quick = run(
+            &solver(|input, _| input.len().to_string()),
+            "abc".into(),
+            budget,
+        );
+        assert!(matches!(quick, Outcome::Solved { answer, .. } if answer == "3"));
+
+        let spins = |_: &str, cancel: &Cancel| {
+            while !cancel.is_cancelled() {
+                thread::sleep(Duration::from_millis(1));
+            }
+            "too late".to_string()
+        };
+        assert_eq!(
+            run(&solver(spins), String::new(), budget),
+            Outcome::TimedOut(budget.time)
+        );
+
+        let hoards = |_: &str, cancel: &Cancel| {
+            let mut hoard = vec![];
+            while !cancel.is_cancelled() {
+                hoard.push(vec![1u8; 1 << 20]);
+                thread::sleep(Duration::from_millis(1));
+            }
+            hoard.len().to_string()
+        };
+        let roomy = Budget {
+            time: Duration::from_secs(10),
+            ..budget
+        };
+        let hoarded = run(&solver(hoards), String::new(), roomy);
+        assert_eq!(hoarded, Outcome::OutOfMemory(budget.memory));
+
+        let traces = |_: &str, _: &Cancel| aoc::trace::Tracer::from_env().is_on().to_string();
+        let path = std::env::temp_dir().join(format!("runner-trace-{}.txt", std::process::id()));
+        let traced = run_traced(&solver(traces), String::new(), roomy, Some(path.clone()));
+        assert!(matches!(traced, Outcome::Solved { answer, .. } if answer == "true"));
+        assert!(path.exists());
+        let _ = std::fs::remove_file(&path);
+        let untraced = run_traced(&solver(traces), String::new(), roomy, None);
+        assert!(matches!(untraced, Outcome::Solved { answer, .. } if answer == "false"));
+
+        let reads = Solver {
+            parse: Some(|input| aoc::parse::Scanner::new(input).int::<u32>().map(drop)),
+            ..solver(|_, _| String::new())
+        };
+        assert_eq!(run_parse(&reads, "12".into(), budget), Ok(Ok(())));
+        let error = run_parse(&reads, "x".into(), budget).unwrap().unwrap_err();
+        assert_eq!(error.message, "expected an integer");
+        let slow = Solver {
+            parse: Some(|_| {
+                thread::sleep(Duration::from_millis(200));
+                Ok(())
+            }),
+            ..solver(|_, _| String::new())
+        };
+        assert_eq!(
+            run_parse(&slow, String::new(), budget),
+            Err(Outcome::TimedOut(budget.time))
+        );
+        assert_eq!(run_parse(&solver(spins), String::new(), budget), Ok(Ok(())));
+
+        // Roomy, as printing a backtrace can take longer than `budget` allows.
+        let panics = run(&solver(|_, _| panic!("boom")), String::new(), roomy);
+        assert_eq!(panics, Outcome::Panicked("boom".to_string()));
+    }
+
+    #[test]
+    fn abandoned_solves_are_tracked() {
+        let budget = Budget {
+            time: Duration::from_millis(50),
+            memory: 64 << 20,
+        };
+        let stubborn = Solver {
+            part: 2,
+            ..solver(|_, _| {
+                thread::sleep(GRACE * 2);
+                "done".to_string()
+            })
+        };
+        assert!(!still_running(&stubborn));
+        assert_eq!(
+            run(&stubborn, String::new(), budget),
+            Outcome::TimedOut(budget.time)
+        );
+        assert!(still_running(&stubborn));
+        thread::sleep(GRACE * 2);
+        assert!(!still_running(&stubborn));
+    }
+}